@@ -0,0 +1,31 @@
+//! Account helpers shared by the vault programs.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Grows an account to `new_len`, the payer tops up the rent
+pub fn realloc<'a>(
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let lamports = account.lamports();
+
+    if rent > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+    Ok(())
+}
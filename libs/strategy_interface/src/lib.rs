@@ -0,0 +1,169 @@
+//! Interface a program has to implement to be used as a strategy by tokenized vaults.
+//!
+//! Instructions are identified by Anchor style discriminators (`sha256("global:<name>")[..8]`),
+//! so an Anchor program implements the interface by exposing instructions with these names,
+//! account orders and arguments:
+//!
+//! | instruction      | accounts                                                                   | args          | return data |
+//! |------------------|----------------------------------------------------------------------------|---------------|-------------|
+//! | `deposit`        | strategy (w), underlying token account, vault (s)                          | `amount: u64` | -           |
+//! | `withdraw`       | strategy (w), underlying token account (w), vault (s), vault token account (w), token program, ..remaining | `amount: u64` | -           |
+//! | `total_assets`   | strategy                                                                   | -             | `u64`       |
+//! | `max_withdraw`   | strategy                                                                   | -             | `u64`       |
+//! | `max_deposit`    | strategy                                                                   | -             | `u64`       |
+//! | `strategy_vault` | strategy                                                                   | -             | `Pubkey`    |
+//!
+//! The vault signs `deposit` and `withdraw` with its PDA. Before `deposit` the vault has already moved
+//! `amount` to the underlying token account, the strategy only records it. On `withdraw` the strategy
+//! sends exactly `amount` to the vault token account and must leave its authorities untouched.
+//! The underlying token account of a strategy is the PDA `["underlying", strategy]` of the strategy program.
+//! Views return little endian `u64` or the 32 bytes of a `Pubkey` through `set_return_data`.
+//!
+//! The strategy program has to be in the transaction for the vault to call it.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke, invoke_signed},
+};
+
+#[error_code]
+pub enum StrategyInterfaceError {
+    #[msg("Strategy returned no or invalid data")]
+    InvalidReturnData,
+}
+
+pub fn discriminator(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+pub struct DepositAccounts<'info> {
+    pub strategy: AccountInfo<'info>,
+    pub underlying_token_account: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+}
+
+pub struct WithdrawAccounts<'info> {
+    pub strategy: AccountInfo<'info>,
+    pub underlying_token_account: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub vault_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+fn meta(account: &AccountInfo) -> AccountMeta {
+    if account.is_writable {
+        AccountMeta::new(account.key(), account.is_signer)
+    } else {
+        AccountMeta::new_readonly(account.key(), account.is_signer)
+    }
+}
+
+pub fn deposit<'info>(
+    program_id: &Pubkey,
+    accounts: DepositAccounts<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = discriminator("deposit").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(accounts.strategy.key(), false),
+            AccountMeta::new_readonly(accounts.underlying_token_account.key(), false),
+            AccountMeta::new_readonly(accounts.vault.key(), true),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.strategy,
+            accounts.underlying_token_account,
+            accounts.vault,
+        ],
+        signer_seeds,
+    ).map_err(Into::into)
+}
+
+pub fn withdraw<'info>(
+    program_id: &Pubkey,
+    accounts: WithdrawAccounts<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+    remaining_accounts: Vec<AccountInfo<'info>>,
+) -> Result<()> {
+    let mut data = discriminator("withdraw").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    let mut metas = vec![
+        AccountMeta::new(accounts.strategy.key(), false),
+        AccountMeta::new(accounts.underlying_token_account.key(), false),
+        AccountMeta::new_readonly(accounts.vault.key(), true),
+        AccountMeta::new(accounts.vault_token_account.key(), false),
+        AccountMeta::new_readonly(accounts.token_program.key(), false),
+    ];
+    metas.extend(remaining_accounts.iter().map(meta));
+
+    let mut infos = vec![
+        accounts.strategy,
+        accounts.underlying_token_account,
+        accounts.vault,
+        accounts.vault_token_account,
+        accounts.token_program,
+    ];
+    infos.extend(remaining_accounts);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: metas,
+        data,
+    };
+
+    invoke_signed(&ix, &infos, signer_seeds).map_err(Into::into)
+}
+
+fn view<'info>(program_id: &Pubkey, name: &str, strategy: &AccountInfo<'info>) -> Result<Vec<u8>> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![AccountMeta::new_readonly(strategy.key(), false)],
+        data: discriminator(name).to_vec(),
+    };
+
+    invoke(&ix, std::slice::from_ref(strategy))?;
+
+    match get_return_data() {
+        Some((returned_by, data)) if returned_by == *program_id => Ok(data),
+        _ => Err(StrategyInterfaceError::InvalidReturnData.into()),
+    }
+}
+
+fn view_u64<'info>(program_id: &Pubkey, name: &str, strategy: &AccountInfo<'info>) -> Result<u64> {
+    let data = view(program_id, name, strategy)?;
+    let bytes: [u8; 8] = data.try_into().map_err(|_| StrategyInterfaceError::InvalidReturnData)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub fn total_assets<'info>(program_id: &Pubkey, strategy: &AccountInfo<'info>) -> Result<u64> {
+    view_u64(program_id, "total_assets", strategy)
+}
+
+pub fn max_withdraw<'info>(program_id: &Pubkey, strategy: &AccountInfo<'info>) -> Result<u64> {
+    view_u64(program_id, "max_withdraw", strategy)
+}
+
+pub fn max_deposit<'info>(program_id: &Pubkey, strategy: &AccountInfo<'info>) -> Result<u64> {
+    view_u64(program_id, "max_deposit", strategy)
+}
+
+pub fn strategy_vault<'info>(program_id: &Pubkey, strategy: &AccountInfo<'info>) -> Result<Pubkey> {
+    let data = view(program_id, "strategy_vault", strategy)?;
+    let bytes: [u8; 32] = data.try_into().map_err(|_| StrategyInterfaceError::InvalidReturnData)?;
+    Ok(Pubkey::new_from_array(bytes))
+}
@@ -1,5 +1,6 @@
 pub const USER_ROLE_SEED: &str = "user_role";
 pub const ROLE_MANAGER_SEED: &str = "role_manager";
+pub const STRATEGY_ROLE_SEED: &str = "strategy_role";
 pub const CONFIG_SEED: &str = "config";
 
 pub const DISCRIMINATOR_LEN: usize = 8;
\ No newline at end of file
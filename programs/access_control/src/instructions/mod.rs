@@ -3,8 +3,10 @@ pub mod initialize;
 pub mod revoke_role;
 pub mod set_role;
 pub mod set_role_manager;
+pub mod strategy_role;
 
 pub use initialize::*;
 pub use revoke_role::*;
 pub use set_role::*;
 pub use set_role_manager::*;
+pub use strategy_role::*;
@@ -49,6 +49,9 @@ pub fn handle_set_role(ctx: Context<SetRole>, role_id: u64, _user: Pubkey) -> Re
     if role.unwrap() == Role::RolesAdmin {
         return Err(ErrorCode::CannotSetRoleAdmin.into());
     }
+    if role.unwrap().is_strategy_scoped() {
+        return Err(ErrorCode::InvalidRoleId.into());
+    }
 
     let role_manager: Role = FromPrimitive::from_u64(ctx.accounts.role_manager.manager_role_id).unwrap();
 
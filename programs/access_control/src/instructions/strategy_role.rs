@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use num_traits::FromPrimitive;
+
+use crate::constants::{DISCRIMINATOR_LEN, ROLE_MANAGER_SEED, STRATEGY_ROLE_SEED, USER_ROLE_SEED};
+use crate::state::{Role, RoleManager, UserRole};
+use crate::errors::ErrorCode;
+
+/// Roles granted for a single strategy, managed like the global ones through the role manager
+#[derive(Accounts)]
+#[instruction(role_id: u64, strategy: Pubkey, user: Pubkey)]
+pub struct SetStrategyRole<'info> {
+    #[account(
+        init_if_needed, 
+        seeds = [
+            STRATEGY_ROLE_SEED.as_bytes(),
+            strategy.as_ref(),
+            user.as_ref(),
+            role_id.to_le_bytes().as_ref()
+        ], 
+        bump,  
+        payer = signer, 
+        space = DISCRIMINATOR_LEN + UserRole::INIT_SPACE,
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(seeds = [ROLE_MANAGER_SEED.as_bytes(), role_id.to_le_bytes().as_ref()], bump)]
+    pub role_manager: Account<'info, RoleManager>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(), 
+            role_manager.manager_role_id.to_le_bytes().as_ref()
+        ], 
+        bump
+    )]
+    pub signer_roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = signer_roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(role_id: u64, strategy: Pubkey, user: Pubkey)]
+pub struct RevokeStrategyRole<'info> {
+    #[account(
+        mut, 
+        seeds = [
+            STRATEGY_ROLE_SEED.as_bytes(),
+            strategy.as_ref(),
+            user.as_ref(),
+            role_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        close = recipient
+    )]
+    pub roles: Account<'info, UserRole>,
+    
+    #[account(seeds = [ROLE_MANAGER_SEED.as_bytes(), role_id.to_le_bytes().as_ref()], bump)]
+    pub role_manager: Account<'info, RoleManager>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(), 
+            role_manager.manager_role_id.to_le_bytes().as_ref()
+        ], 
+        bump
+    )]
+    pub signer_roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = signer_roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_strategy_role(ctx: Context<SetStrategyRole>, role_id: u64, _strategy: Pubkey, _user: Pubkey) -> Result<()> {
+    let role: Option<Role> = FromPrimitive::from_u64(role_id);
+    match role {
+        Some(role) if role.is_strategy_scoped() => {
+            ctx.accounts.roles.has_role = true;
+            Ok(())
+        }
+        _ => Err(ErrorCode::InvalidRoleId.into()),
+    }
+}
+
+pub fn handle_revoke_strategy_role(_ctx: Context<RevokeStrategyRole>, _role_id: u64, _strategy: Pubkey, _user: Pubkey) -> Result<()> {
+    Ok(())
+}
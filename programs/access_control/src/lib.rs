@@ -28,4 +28,12 @@ pub mod access_control {
     pub fn revoke_role(ctx: Context<RevokeRole>, role_id: u64, user: Pubkey) -> Result<()> {
         handle_revoke_role(ctx, role_id, user)
     }
+
+    pub fn set_strategy_role(ctx: Context<SetStrategyRole>, role_id: u64, strategy: Pubkey, user: Pubkey) -> Result<()> {
+        handle_set_strategy_role(ctx, role_id, strategy, user)
+    }
+
+    pub fn revoke_strategy_role(ctx: Context<RevokeStrategyRole>, role_id: u64, strategy: Pubkey, user: Pubkey) -> Result<()> {
+        handle_revoke_strategy_role(ctx, role_id, strategy, user)
+    }
 }
\ No newline at end of file
@@ -28,10 +28,21 @@ pub enum Role {
     AccountantAdmin,
     KYCProvider,
     KYCVerified,
+    HealthCheckOverrider,
+    Guardian,
+    EmergencyAdmin,
+    // scoped to a single strategy, granted with set_strategy_role
+    StrategyKeeper,
+    StrategyManager,
+    StrategyFeeAdmin,
 }
 
 impl Role {
     pub fn to_seed(self) -> [u8; 8] {
         (self as u64).to_le_bytes()
     }
+
+    pub fn is_strategy_scoped(self) -> bool {
+        matches!(self, Role::StrategyKeeper | Role::StrategyManager | Role::StrategyFeeAdmin)
+    }
 }
\ No newline at end of file
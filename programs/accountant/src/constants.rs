@@ -1,3 +1,9 @@
 pub const FEE_BPS: u64 = 10_000;
 pub const CONFIG_SEED: &str = "config";
 pub const DISCRIMINATOR_LEN: usize = 8;
+
+pub const SECS_PER_YEAR: u64 = 31_556_952;
+pub const MAX_VAULT_FEE_CONFIGS: usize = 32;
+
+pub const MAX_PERFORMANCE_FEE: u64 = 5_000;
+pub const MAX_MANAGEMENT_FEE: u64 = 200;
@@ -13,4 +13,34 @@ pub enum ErrorCode {
 
     #[msg("Invalid recipient")]
     InvalidRecipient,
+
+    #[msg("Gain is too high")]
+    TooMuchGain,
+
+    #[msg("Loss is too high")]
+    TooMuchLoss,
+
+    #[msg("Max vault fee configs reached")]
+    VaultFeeConfigsFull,
+
+    #[msg("Vault fee config not found")]
+    VaultFeeConfigNotFound,
+
+    #[msg("Fee exceeds the maximum")]
+    InvalidFee,
+
+    #[msg("Fee increase must be proposed")]
+    FeeIncreaseTimelocked,
+
+    #[msg("No pending fee change")]
+    NoPendingFeeChange,
+
+    #[msg("Fee change delay has not passed")]
+    FeeChangeDelayNotPassed,
+
+    #[msg("A fee change is already pending")]
+    FeeChangePending,
+
+    #[msg("Account is already migrated")]
+    AlreadyMigrated,
 }
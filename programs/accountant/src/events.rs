@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::FeeConfig;
+
+#[event]
+pub struct FeeConfigUpdatedEvent {
+    pub accountant_key: Pubkey,
+    /// None means the default config
+    pub vault: Option<Pubkey>,
+    pub config: FeeConfig,
+}
+
+#[event]
+pub struct VaultFeeConfigRemovedEvent {
+    pub accountant_key: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct FeeRecipientUpdatedEvent {
+    pub accountant_key: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct FeeChangeDelayUpdatedEvent {
+    pub accountant_key: Pubkey,
+    pub delay: u64,
+}
+
+#[event]
+pub struct FeeChangeProposedEvent {
+    pub accountant_key: Pubkey,
+    pub vault: Option<Pubkey>,
+    pub config: FeeConfig,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct FeeChangeDelayProposedEvent {
+    pub accountant_key: Pubkey,
+    pub delay: u64,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct FeeChangeCancelledEvent {
+    pub accountant_key: Pubkey,
+    pub vault: Option<Pubkey>,
+    pub config: FeeConfig,
+}
+
+#[event]
+pub struct AccountantMigratedEvent {
+    pub account_key: Pubkey,
+    pub old_len: u64,
+    pub new_len: u64,
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::events::FeeConfigUpdatedEvent;
+use crate::instructions::SetFeeConfig;
+use crate::utils::unchecked_accountant::UncheckedAccountant;
+
+pub fn handle_apply_fee(ctx: Context<SetFeeConfig>) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    let applied = accountant.apply_fee_config()?;
+
+    emit!(FeeConfigUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault: applied.vault,
+        config: applied.config,
+    });
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::events::FeeChangeCancelledEvent;
+use crate::instructions::SetFeeConfig;
+use crate::utils::unchecked_accountant::UncheckedAccountant;
+
+/// Drops the pending fee config, a new one can be proposed afterwards
+pub fn handle_cancel_fee(ctx: Context<SetFeeConfig>) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    let cancelled = accountant.cancel_fee_config()?;
+
+    emit!(FeeChangeCancelledEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault: cancelled.vault,
+        config: cancelled.config,
+    });
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::error::ErrorCode;
+use crate::events::AccountantMigratedEvent;
+use crate::state::AccountantType;
+use crate::utils::unchecked_accountant::UncheckedAccountant;
+
+#[derive(Accounts)]
+pub struct MigrateAccountant<'info> {
+    /// CHECK: can be any accountant, the layout is resolved by discriminator and size
+    #[account(mut, owner = crate::ID)]
+    pub accountant: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::AccountantAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub access_control: Program<'info, AccessControl>,
+}
+
+/// Reallocs an accountant created with the legacy layout and rewrites it in the current one
+pub fn handle_migrate_accountant(ctx: Context<MigrateAccountant>) -> Result<()> {
+    let accountant = ctx.accounts.accountant.from_unchecked()?;
+
+    let accountant_info = ctx.accounts.accountant.to_account_info();
+    let old_len = accountant_info.data_len();
+    let new_len = AccountantType::Generic.space();
+
+    if old_len >= new_len {
+        return Err(ErrorCode::AlreadyMigrated.into());
+    }
+
+    account_utils::realloc(
+        &accountant_info,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    accountant.save_changes(&mut &mut accountant_info.try_borrow_mut_data()?[8..])?;
+
+    emit!(
+        AccountantMigratedEvent {
+            account_key: accountant_info.key(),
+            old_len: old_len as u64,
+            new_len: new_len as u64,
+        }
+    );
+
+    Ok(())
+}
@@ -1,11 +1,21 @@
+pub mod apply_fee;
+pub mod cancel_fee;
 pub mod distribute;
 pub mod init_accountant;
 pub mod initialize;
+pub mod migrate_accountant;
+pub mod propose_fee;
 pub mod set_fee;
+pub mod set_fee_config;
 pub mod set_fee_recipient;
 
+pub use apply_fee::*;
+pub use cancel_fee::*;
 pub use distribute::*;
 pub use init_accountant::*;
 pub use initialize::*;
+pub use migrate_accountant::*;
+pub use propose_fee::*;
 pub use set_fee::*;
-pub use set_fee_recipient::*;
\ No newline at end of file
+pub use set_fee_config::*;
+pub use set_fee_recipient::*;
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::events::FeeChangeProposedEvent;
+use crate::instructions::SetFeeConfig;
+use crate::state::FeeConfig;
+use crate::utils::unchecked_accountant::UncheckedAccountant;
+
+/// Proposes a new fee config for the vault (or the default one if `vault` is None),
+/// it can be applied once the fee change delay has passed
+pub fn handle_propose_fee(
+    ctx: Context<SetFeeConfig>, 
+    vault: Option<Pubkey>,
+    config: FeeConfig,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    let pending = accountant.propose_fee_config(vault, config)?;
+
+    emit!(FeeChangeProposedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault,
+        config,
+        effective_at: pending.effective_at,
+    });
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
@@ -5,6 +5,7 @@ use access_control::{
     state::{UserRole, Role}
 };
 
+use crate::events::FeeConfigUpdatedEvent;
 use crate::utils::unchecked_accountant::UncheckedAccountant;
 
 #[derive(Accounts)]
@@ -37,5 +38,12 @@ pub fn handle_set_fee(
     let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
 
     accountant.set_fee(fee)?;
+
+    emit!(FeeConfigUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault: None,
+        config: accountant.default_fee_config(),
+    });
+
     accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
 }
\ No newline at end of file
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::events::{
+    FeeChangeDelayProposedEvent,
+    FeeChangeDelayUpdatedEvent,
+    FeeConfigUpdatedEvent,
+    VaultFeeConfigRemovedEvent,
+};
+use crate::state::FeeConfig;
+use crate::utils::unchecked_accountant::UncheckedAccountant;
+
+/// Accounts of all fee config changes, including the timelocked propose/apply flow
+#[derive(Accounts)]
+pub struct SetFeeConfig<'info> {
+    /// CHECK: can be any accountant
+    #[account(mut)]
+    pub accountant: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::AccountantAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+pub fn handle_set_default_fee_config(
+    ctx: Context<SetFeeConfig>, 
+    config: FeeConfig,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    accountant.set_default_fee_config(config)?;
+
+    emit!(FeeConfigUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault: None,
+        config,
+    });
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
+
+pub fn handle_set_vault_fee_config(
+    ctx: Context<SetFeeConfig>, 
+    vault: Pubkey,
+    config: FeeConfig,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    accountant.set_vault_fee_config(vault, config)?;
+
+    emit!(FeeConfigUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault: Some(vault),
+        config,
+    });
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
+
+pub fn handle_remove_vault_fee_config(
+    ctx: Context<SetFeeConfig>, 
+    vault: Pubkey,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    accountant.remove_vault_fee_config(vault)?;
+
+    emit!(VaultFeeConfigRemovedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault,
+    });
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
+
+pub fn handle_set_fee_change_delay(
+    ctx: Context<SetFeeConfig>, 
+    delay: u64,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    match accountant.set_fee_change_delay(delay)? {
+        Some(pending) => emit!(FeeChangeDelayProposedEvent {
+            accountant_key: ctx.accounts.accountant.key(),
+            delay,
+            effective_at: pending.effective_at,
+        }),
+        None => emit!(FeeChangeDelayUpdatedEvent {
+            accountant_key: ctx.accounts.accountant.key(),
+            delay,
+        }),
+    }
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
+
+pub fn handle_apply_fee_change_delay(ctx: Context<SetFeeConfig>) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    let applied = accountant.apply_fee_change_delay()?;
+
+    emit!(FeeChangeDelayUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        delay: applied.delay,
+    });
+
+    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+}
@@ -5,6 +5,7 @@ use access_control::{
     state::{UserRole, Role}
 };
 
+use crate::events::FeeRecipientUpdatedEvent;
 use crate::utils::unchecked_accountant::UncheckedAccountant;
 
 #[derive(Accounts)]
@@ -37,5 +38,11 @@ pub fn handle_set_fee_recipient(
     let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
 
     accountant.set_fee_recipient(recipient)?;
+
+    emit!(FeeRecipientUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        recipient,
+    });
+
     accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -37,6 +38,10 @@ pub mod accountant {
         handle_init_accountant(ctx, accountant_type)
     }
 
+    pub fn migrate_accountant(ctx: Context<MigrateAccountant>) -> Result<()> {
+        handle_migrate_accountant(ctx)
+    }
+
     pub fn distribute(ctx: Context<Distribute>) -> Result<()> {
         handle_distribute(ctx)
     }
@@ -48,4 +53,36 @@ pub mod accountant {
     pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>, recipient: Pubkey) -> Result<()> {
         handle_set_fee_recipient(ctx, recipient)
     }
+
+    pub fn set_default_fee_config(ctx: Context<SetFeeConfig>, config: FeeConfig) -> Result<()> {
+        handle_set_default_fee_config(ctx, config)
+    }
+
+    pub fn set_vault_fee_config(ctx: Context<SetFeeConfig>, vault: Pubkey, config: FeeConfig) -> Result<()> {
+        handle_set_vault_fee_config(ctx, vault, config)
+    }
+
+    pub fn remove_vault_fee_config(ctx: Context<SetFeeConfig>, vault: Pubkey) -> Result<()> {
+        handle_remove_vault_fee_config(ctx, vault)
+    }
+
+    pub fn set_fee_change_delay(ctx: Context<SetFeeConfig>, delay: u64) -> Result<()> {
+        handle_set_fee_change_delay(ctx, delay)
+    }
+
+    pub fn apply_fee_change_delay(ctx: Context<SetFeeConfig>) -> Result<()> {
+        handle_apply_fee_change_delay(ctx)
+    }
+
+    pub fn propose_fee(ctx: Context<SetFeeConfig>, vault: Option<Pubkey>, config: FeeConfig) -> Result<()> {
+        handle_propose_fee(ctx, vault, config)
+    }
+
+    pub fn apply_fee(ctx: Context<SetFeeConfig>) -> Result<()> {
+        handle_apply_fee(ctx)
+    }
+
+    pub fn cancel_fee(ctx: Context<SetFeeConfig>) -> Result<()> {
+        handle_cancel_fee(ctx)
+    }
 }
@@ -1,19 +1,35 @@
 use anchor_lang::prelude::*;
 
 use crate::instructions::Distribute;
-use crate::state::GenericAccountant;
+use crate::state::{FeeConfig, GenericAccountant, PendingFeeChangeDelay, PendingFeeConfig};
 
 const DISCRIMINATOR_LEN: usize = 8;
 
 pub trait Accountant {
     fn init(&mut self, index: u64, bump: u8) -> Result<()>;
 
-    fn report(&self, profit: u64, loss: u64) -> Result<(u64,u64)>;
+    /// Fails if the report is outside of the max gain/loss of the vault fee config
+    fn check_health(&self, vault: Pubkey, profit: u64, loss: u64, current_debt: u64) -> Result<()>;
+    fn report(&self, vault: Pubkey, profit: u64, loss: u64, current_debt: u64, duration: u64) -> Result<(u64,u64)>;
     fn distribute(&mut self, accounts: &Distribute) -> Result<()>;
     fn set_fee_recipient(&mut self, recipient: Pubkey) -> Result<()>;
     fn set_fee(&mut self, fee: u64) -> Result<()>;
-
-    fn performance_fee(&self) -> u64;
+    fn set_default_fee_config(&mut self, config: FeeConfig) -> Result<()>;
+    fn set_vault_fee_config(&mut self, vault: Pubkey, config: FeeConfig) -> Result<()>;
+    fn remove_vault_fee_config(&mut self, vault: Pubkey) -> Result<()>;
+    /// Raises apply immediately, decreases are returned as pending until the current delay has passed
+    fn set_fee_change_delay(&mut self, delay: u64) -> Result<Option<PendingFeeChangeDelay>>;
+    fn apply_fee_change_delay(&mut self) -> Result<PendingFeeChangeDelay>;
+    fn propose_fee_config(&mut self, vault: Option<Pubkey>, config: FeeConfig) -> Result<PendingFeeConfig>;
+    fn apply_fee_config(&mut self) -> Result<PendingFeeConfig>;
+    fn cancel_fee_config(&mut self) -> Result<PendingFeeConfig>;
+
+    fn default_fee_config(&self) -> FeeConfig;
+    /// Returns the fee config of the vault, falls back to the default one
+    fn fee_config(&self, vault: Pubkey) -> FeeConfig;
+    fn performance_fee(&self, vault: Pubkey) -> u64 {
+        self.fee_config(vault).performance_fee
+    }
     fn fee_recipient(&self) -> Pubkey;
 
     fn seeds(&self) -> [&[u8]; 2];
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FEE_BPS, MAX_MANAGEMENT_FEE, MAX_PERFORMANCE_FEE};
+use crate::error::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, Clone, Copy, InitSpace)]
+pub struct FeeConfig {
+    /// Fee charged on reported profit, in bps
+    pub performance_fee: u64,
+    /// Annual fee charged on the strategy debt, in bps
+    pub management_fee: u64,
+    /// Upper bound of total fees as a share of the profit, in bps. 0 means no cap
+    pub max_fee: u64,
+    /// Max profit a single report can have relative to the current debt, in bps. 0 means no limit
+    pub max_gain: u64,
+    /// Max loss a single report can have relative to the current debt, in bps. 0 means no limit
+    pub max_loss: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, Clone, Copy, InitSpace)]
+pub struct VaultFeeConfig {
+    pub vault: Pubkey,
+    pub config: FeeConfig,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, Clone, Copy, InitSpace)]
+pub struct PendingFeeConfig {
+    /// None means the default config
+    pub vault: Option<Pubkey>,
+    pub config: FeeConfig,
+    pub effective_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, Clone, Copy, InitSpace)]
+pub struct PendingFeeChangeDelay {
+    pub delay: u64,
+    pub effective_at: i64,
+}
+
+impl FeeConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.performance_fee > MAX_PERFORMANCE_FEE 
+            || self.management_fee > MAX_MANAGEMENT_FEE 
+            || self.max_fee > FEE_BPS 
+        {
+            return Err(ErrorCode::InvalidFee.into());
+        }
+        Ok(())
+    }
+
+    /// Returns true if any of the fees is higher than in `current`
+    pub fn raises_fees(&self, current: &FeeConfig) -> bool {
+        self.performance_fee > current.performance_fee 
+            || self.management_fee > current.management_fee 
+            || self.fee_cap() > current.fee_cap()
+    }
+
+    /// max_fee of 0 means the fees are not capped
+    fn fee_cap(&self) -> u64 {
+        if self.max_fee == 0 { u64::MAX } else { self.max_fee }
+    }
+}
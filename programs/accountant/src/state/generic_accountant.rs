@@ -2,9 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
 
 use crate::state::base_accountant::Accountant;
+use crate::state::fee_config::{FeeConfig, PendingFeeChangeDelay, PendingFeeConfig, VaultFeeConfig};
 use crate::instructions::Distribute;
 use crate::error::ErrorCode;
-use crate::constants::FEE_BPS;
+use crate::constants::{FEE_BPS, MAX_VAULT_FEE_CONFIGS, SECS_PER_YEAR};
 
 #[account]
 #[derive(Default, Debug, InitSpace)]
@@ -12,8 +13,20 @@ pub struct GenericAccountant {
     pub index_buffer: [u8; 8],
     pub bump: [u8; 1],
 
+    /// mirrors `default_config.performance_fee`, kept in place so the legacy layout stays readable
     pub performance_fee: u64,
     pub fee_recipient: Pubkey,
+
+    /// used for vaults that have no config of their own
+    pub default_config: FeeConfig,
+    #[max_len(MAX_VAULT_FEE_CONFIGS)]
+    pub vault_configs: Vec<VaultFeeConfig>,
+
+    /// fee increases have to wait this many seconds after being proposed
+    pub fee_change_delay: u64,
+    pub pending_fee_config: Option<PendingFeeConfig>,
+    /// lowering the delay is timelocked by the current delay
+    pub pending_fee_change_delay: Option<PendingFeeChangeDelay>,
 }
 
 impl Accountant for GenericAccountant {
@@ -30,8 +43,37 @@ impl Accountant for GenericAccountant {
         ]
     }
 
-    fn report(&self, profit: u64, _loss: u64) -> Result<(u64, u64)> {
-        let total_fees = self.performance_fee * profit / FEE_BPS;
+    fn check_health(&self, vault: Pubkey, profit: u64, loss: u64, current_debt: u64) -> Result<()> {
+        let config = self.fee_config(vault);
+
+        if config.max_gain > 0 && profit > bps_of(current_debt, config.max_gain) {
+            return Err(ErrorCode::TooMuchGain.into());
+        }
+
+        if config.max_loss > 0 && loss > bps_of(current_debt, config.max_loss) {
+            return Err(ErrorCode::TooMuchLoss.into());
+        }
+
+        Ok(())
+    }
+
+    fn report(&self, vault: Pubkey, profit: u64, _loss: u64, current_debt: u64, duration: u64) -> Result<(u64, u64)> {
+        let config = self.fee_config(vault);
+
+        let management_fees = (current_debt as u128 * duration as u128 * config.management_fee as u128
+            / FEE_BPS as u128
+            / SECS_PER_YEAR as u128) as u64;
+        let mut total_fees = management_fees;
+
+        if profit > 0 {
+            total_fees += bps_of(profit, config.performance_fee);
+
+            // fees can never take more than max_fee of the profit
+            if config.max_fee > 0 {
+                total_fees = std::cmp::min(total_fees, bps_of(profit, config.max_fee));
+            }
+        }
+
         let total_refunds = 0;
         Ok((total_fees, total_refunds))
     }
@@ -58,17 +100,126 @@ impl Accountant for GenericAccountant {
     }
 
     fn set_fee(&mut self, fee: u64) -> Result<()> {
-        self.performance_fee = fee;
+        let config = FeeConfig {
+            performance_fee: fee,
+            ..self.default_config
+        };
+        self.validate_immediate_change(&config, &self.default_config)?;
+
+        self.write_default_config(config);
+        Ok(())
+    }
+
+    fn set_default_fee_config(&mut self, config: FeeConfig) -> Result<()> {
+        self.validate_immediate_change(&config, &self.default_config)?;
+
+        self.write_default_config(config);
+        Ok(())
+    }
+
+    fn set_vault_fee_config(&mut self, vault: Pubkey, config: FeeConfig) -> Result<()> {
+        self.validate_immediate_change(&config, &self.fee_config(vault))?;
+        self.write_vault_fee_config(vault, config)
+    }
+
+    fn remove_vault_fee_config(&mut self, vault: Pubkey) -> Result<()> {
+        let vault_config = self.fee_config(vault);
+        self.validate_immediate_change(&self.default_config, &vault_config)?;
+
+        let len = self.vault_configs.len();
+        self.vault_configs.retain(|c| c.vault != vault);
+
+        if self.vault_configs.len() == len {
+            return Err(ErrorCode::VaultFeeConfigNotFound.into());
+        }
         Ok(())
     }
 
+    fn set_fee_change_delay(&mut self, delay: u64) -> Result<Option<PendingFeeChangeDelay>> {
+        // raising the delay only makes fee changes slower, it also drops a pending decrease
+        if delay >= self.fee_change_delay {
+            self.fee_change_delay = delay;
+            self.pending_fee_change_delay = None;
+            return Ok(None);
+        }
+
+        let pending = PendingFeeChangeDelay {
+            delay,
+            effective_at: Clock::get()?.unix_timestamp + self.fee_change_delay as i64,
+        };
+        self.pending_fee_change_delay = Some(pending);
+
+        Ok(Some(pending))
+    }
+
+    fn apply_fee_change_delay(&mut self) -> Result<PendingFeeChangeDelay> {
+        let pending = self.pending_fee_change_delay.ok_or(ErrorCode::NoPendingFeeChange)?;
+
+        if Clock::get()?.unix_timestamp < pending.effective_at {
+            return Err(ErrorCode::FeeChangeDelayNotPassed.into());
+        }
+
+        self.fee_change_delay = pending.delay;
+        self.pending_fee_change_delay = None;
+
+        Ok(pending)
+    }
+
+    fn propose_fee_config(&mut self, vault: Option<Pubkey>, config: FeeConfig) -> Result<PendingFeeConfig> {
+        config.validate()?;
+
+        if self.pending_fee_config.is_some() {
+            return Err(ErrorCode::FeeChangePending.into());
+        }
+
+        let pending = PendingFeeConfig {
+            vault,
+            config,
+            effective_at: Clock::get()?.unix_timestamp + self.fee_change_delay as i64,
+        };
+        self.pending_fee_config = Some(pending);
+
+        Ok(pending)
+    }
+
+    fn apply_fee_config(&mut self) -> Result<PendingFeeConfig> {
+        let pending = self.pending_fee_config.ok_or(ErrorCode::NoPendingFeeChange)?;
+
+        if Clock::get()?.unix_timestamp < pending.effective_at {
+            return Err(ErrorCode::FeeChangeDelayNotPassed.into());
+        }
+
+        match pending.vault {
+            Some(vault) => self.write_vault_fee_config(vault, pending.config)?,
+            None => self.write_default_config(pending.config),
+        }
+        self.pending_fee_config = None;
+
+        Ok(pending)
+    }
+
+    fn cancel_fee_config(&mut self) -> Result<PendingFeeConfig> {
+        let pending = self.pending_fee_config.ok_or(ErrorCode::NoPendingFeeChange)?;
+        self.pending_fee_config = None;
+
+        Ok(pending)
+    }
+
     fn set_fee_recipient(&mut self, recipient: Pubkey) -> Result<()> {
         self.fee_recipient = recipient;
         Ok(())
     }
 
-    fn performance_fee(&self) -> u64 {
-        self.performance_fee
+    fn default_fee_config(&self) -> FeeConfig {
+        self.default_config
+    }
+
+    fn fee_config(&self, vault: Pubkey) -> FeeConfig {
+        self.vault_configs
+            .iter()
+            .find(|c| c.vault == vault)
+            .map(|c| c.config)
+            .unwrap_or(self.default_config)
     }
 
     fn fee_recipient(&self) -> Pubkey {
@@ -82,3 +233,65 @@ impl Accountant for GenericAccountant {
     }
     
 }
+
+impl GenericAccountant {
+    /// Fee changes outside of propose/apply are only allowed if they don't raise fees,
+    /// unless the timelock is disabled
+    fn validate_immediate_change(&self, config: &FeeConfig, current: &FeeConfig) -> Result<()> {
+        config.validate()?;
+
+        if self.fee_change_delay > 0 && config.raises_fees(current) {
+            return Err(ErrorCode::FeeIncreaseTimelocked.into());
+        }
+        Ok(())
+    }
+
+    fn write_default_config(&mut self, config: FeeConfig) {
+        self.performance_fee = config.performance_fee;
+        self.default_config = config;
+    }
+
+    fn write_vault_fee_config(&mut self, vault: Pubkey, config: FeeConfig) -> Result<()> {
+        if let Some(vault_config) = self.vault_configs.iter_mut().find(|c| c.vault == vault) {
+            vault_config.config = config;
+            return Ok(());
+        }
+
+        if self.vault_configs.len() >= MAX_VAULT_FEE_CONFIGS {
+            return Err(ErrorCode::VaultFeeConfigsFull.into());
+        }
+
+        self.vault_configs.push(VaultFeeConfig { vault, config });
+        Ok(())
+    }
+}
+
+/// Layout of generic accountants created before fee configs were introduced.
+/// It is only used to read old accounts, `migrate_accountant` rewrites them in the current layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, InitSpace)]
+pub struct GenericAccountantV0 {
+    pub index_buffer: [u8; 8],
+    pub bump: [u8; 1],
+    pub performance_fee: u64,
+    pub fee_recipient: Pubkey,
+}
+
+impl From<GenericAccountantV0> for GenericAccountant {
+    fn from(legacy: GenericAccountantV0) -> Self {
+        GenericAccountant {
+            index_buffer: legacy.index_buffer,
+            bump: legacy.bump,
+            performance_fee: legacy.performance_fee,
+            fee_recipient: legacy.fee_recipient,
+            default_config: FeeConfig {
+                performance_fee: legacy.performance_fee,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+fn bps_of(amount: u64, bps: u64) -> u64 {
+    (amount as u128 * bps as u128 / FEE_BPS as u128) as u64
+}
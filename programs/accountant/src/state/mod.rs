@@ -1,7 +1,9 @@
 pub mod base_accountant;
 pub mod config;
+pub mod fee_config;
 pub mod generic_accountant;  
 
 pub use base_accountant::*;
 pub use config::*;
-pub use generic_accountant::*;
\ No newline at end of file
+pub use fee_config::*;
+pub use generic_accountant::*;
@@ -2,8 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
 
 use crate::state::*;
+use crate::constants::DISCRIMINATOR_LEN;
 use crate::error::ErrorCode;
-use crate::state::GenericAccountant;
+use crate::state::{GenericAccountant, GenericAccountantV0};
 
 pub trait UncheckedAccountant {
     fn get_discriminator(&self) -> Result<[u8; 8]>;
@@ -26,9 +27,15 @@ impl<'a> UncheckedAccountant for UncheckedAccount<'a> {
 
         match discriminator {
             GenericAccountant::DISCRIMINATOR => {
-                let strategy = GenericAccountant::try_from_slice(&data[8..])
-                    .map_err(|_| ErrorCode::InvalidData)?;
-                Ok(Box::new(strategy))
+                // accounts are allocated for the max number of vault configs, so the data can have trailing bytes
+                let accountant = if data.len() == DISCRIMINATOR_LEN + GenericAccountantV0::INIT_SPACE {
+                    GenericAccountantV0::deserialize(&mut &data[8..])
+                        .map(GenericAccountant::from)
+                } else {
+                    GenericAccountant::deserialize(&mut &data[8..])
+                }
+                .map_err(|_| ErrorCode::InvalidData)?;
+                Ok(Box::new(accountant))
             }
             _ => {
                 msg!("Invalid discriminator");
@@ -53,7 +60,7 @@ impl<'a> UncheckedAccountant for UncheckedAccount<'a> {
 
 //     match discriminator {
 //         GenericAccountant::DISCRIMINATOR => {
-//             let strategy = GenericAccountant::try_from_slice(&strategy_data[8..])
+//             let strategy = GenericAccountant::deserialize(&mut &strategy_data[8..])
 //                 .map_err(|_| ErrorCode::InvalidData)?;
 //             Ok(Box::new(strategy))
 //         }
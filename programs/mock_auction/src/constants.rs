@@ -0,0 +1,8 @@
+pub const AUCTION_SEED: &str = "auction";
+pub const ORACLE_SEED: &str = "oracle";
+pub const UNDERLYING_VAULT_SEED: &str = "underlying";
+pub const COLLATERAL_VAULT_SEED: &str = "collateral";
+
+/// Prices are the amount of underlying per collateral token scaled by this value
+pub const PRICE_SCALE: u64 = 1_000_000_000;
+pub const BPS: u64 = 10_000;
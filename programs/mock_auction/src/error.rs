@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer has no access")]
+    AccessDenied,
+
+    #[msg("Invalid price")]
+    InvalidPrice,
+
+    #[msg("Invalid discount")]
+    InvalidDiscount,
+
+    #[msg("Amount is zero")]
+    ZeroAmount,
+
+    #[msg("Not enough liquidity in the auction")]
+    InsufficientLiquidity,
+}
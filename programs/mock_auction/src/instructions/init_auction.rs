@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::{AUCTION_SEED, BPS, COLLATERAL_VAULT_SEED, UNDERLYING_VAULT_SEED};
+use crate::error::ErrorCode;
+use crate::state::{Auction, PriceOracle};
+
+/// The owner funds the collateral vault with collateral to liquidate
+/// and the underlying vault with liquidity for buybacks
+#[derive(Accounts)]
+pub struct InitAuction<'info> {
+    #[account(
+        init, 
+        seeds = [AUCTION_SEED.as_bytes(), oracle.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        space = 8 + Auction::INIT_SPACE
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account(has_one = collateral_mint, has_one = underlying_mint)]
+    pub oracle: Box<Account<'info, PriceOracle>>,
+
+    #[account(
+        init, 
+        seeds = [UNDERLYING_VAULT_SEED.as_bytes(), auction.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        token::mint = underlying_mint, 
+        token::authority = auction,
+    )]
+    pub underlying_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init, 
+        seeds = [COLLATERAL_VAULT_SEED.as_bytes(), auction.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        token::mint = collateral_mint, 
+        token::authority = auction,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub underlying_mint: Box<Account<'info, Mint>>,
+
+    #[account()]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_init_auction(ctx: Context<InitAuction>, discount_bps: u64) -> Result<()> {
+    if discount_bps >= BPS {
+        return Err(ErrorCode::InvalidDiscount.into());
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.bump = [ctx.bumps.auction];
+    auction.owner = ctx.accounts.signer.key();
+    auction.oracle = ctx.accounts.oracle.key();
+    auction.underlying_mint = ctx.accounts.underlying_mint.key();
+    auction.collateral_mint = ctx.accounts.collateral_mint.key();
+    auction.underlying_vault = ctx.accounts.underlying_vault.key();
+    auction.collateral_vault = ctx.accounts.collateral_vault.key();
+    auction.discount_bps = discount_bps;
+
+    Ok(())
+}
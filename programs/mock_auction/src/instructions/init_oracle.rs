@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::constants::ORACLE_SEED;
+use crate::error::ErrorCode;
+use crate::state::PriceOracle;
+
+#[derive(Accounts)]
+pub struct InitOracle<'info> {
+    #[account(
+        init, 
+        seeds = [ORACLE_SEED.as_bytes(), collateral_mint.key().as_ref(), underlying_mint.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        space = 8 + PriceOracle::INIT_SPACE
+    )]
+    pub oracle: Account<'info, PriceOracle>,
+
+    #[account()]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account()]
+    pub underlying_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_init_oracle(ctx: Context<InitOracle>, price: u64) -> Result<()> {
+    if price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    let oracle = &mut ctx.accounts.oracle;
+    oracle.owner = ctx.accounts.signer.key();
+    oracle.collateral_mint = ctx.accounts.collateral_mint.key();
+    oracle.underlying_mint = ctx.accounts.underlying_mint.key();
+    oracle.price = price;
+    oracle.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
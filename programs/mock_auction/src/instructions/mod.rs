@@ -0,0 +1,9 @@
+pub mod init_auction;
+pub mod init_oracle;
+pub mod set_price;
+pub mod trade;
+
+pub use init_auction::*;
+pub use init_oracle::*;
+pub use set_price::*;
+pub use trade::*;
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::PriceOracle;
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    #[account(mut, has_one = owner @ErrorCode::AccessDenied)]
+    pub oracle: Account<'info, PriceOracle>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_price(ctx: Context<SetPrice>, price: u64) -> Result<()> {
+    if price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    let oracle = &mut ctx.accounts.oracle;
+    oracle.price = price;
+    oracle.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
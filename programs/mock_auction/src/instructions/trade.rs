@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::ErrorCode;
+use crate::state::{Auction, PriceOracle};
+
+#[derive(Accounts)]
+pub struct Trade<'info> {
+    #[account(has_one = oracle, has_one = underlying_vault, has_one = collateral_vault)]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account()]
+    pub oracle: Box<Account<'info, PriceOracle>>,
+
+    #[account(mut)]
+    pub underlying_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = auction.underlying_mint)]
+    pub user_underlying_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = auction.collateral_mint)]
+    pub user_collateral_account: Box<Account<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Buys discounted collateral for `underlying_amount`
+pub fn handle_bid(ctx: Context<Trade>, underlying_amount: u64) -> Result<()> {
+    let collateral = ctx.accounts.auction.collateral_for(underlying_amount, ctx.accounts.oracle.price);
+    if collateral == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    if ctx.accounts.collateral_vault.amount < collateral {
+        return Err(ErrorCode::InsufficientLiquidity.into());
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_underlying_account.to_account_info(),
+                to: ctx.accounts.underlying_vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        underlying_amount,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                to: ctx.accounts.user_collateral_account.to_account_info(),
+                authority: ctx.accounts.auction.to_account_info(),
+            },
+            &[&ctx.accounts.auction.seeds()],
+        ),
+        collateral,
+    )
+}
+
+/// Sells collateral back at the oracle price
+pub fn handle_sell(ctx: Context<Trade>, collateral_amount: u64) -> Result<()> {
+    let proceeds = ctx.accounts.oracle.collateral_value(collateral_amount);
+    if proceeds == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    if ctx.accounts.underlying_vault.amount < proceeds {
+        return Err(ErrorCode::InsufficientLiquidity.into());
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_collateral_account.to_account_info(),
+                to: ctx.accounts.collateral_vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        collateral_amount,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.underlying_vault.to_account_info(),
+                to: ctx.accounts.user_underlying_account.to_account_info(),
+                authority: ctx.accounts.auction.to_account_info(),
+            },
+            &[&ctx.accounts.auction.seeds()],
+        ),
+        proceeds,
+    )
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod instructions;
+pub mod state;
+pub mod error;
+
+pub use constants::*;
+pub use instructions::*;
+pub use state::*;
+
+declare_id!("A7Bc8k3WSVvZa3wxDuXGjgZJJ7wTJokzZHrh19Rph2wK");
+
+#[program]
+pub mod mock_auction {
+    use super::*;
+
+    pub fn init_oracle(ctx: Context<InitOracle>, price: u64) -> Result<()> {
+        handle_init_oracle(ctx, price)
+    }
+
+    pub fn set_price(ctx: Context<SetPrice>, price: u64) -> Result<()> {
+        handle_set_price(ctx, price)
+    }
+
+    pub fn init_auction(ctx: Context<InitAuction>, discount_bps: u64) -> Result<()> {
+        handle_init_auction(ctx, discount_bps)
+    }
+
+    pub fn bid(ctx: Context<Trade>, underlying_amount: u64) -> Result<()> {
+        handle_bid(ctx, underlying_amount)
+    }
+
+    pub fn sell(ctx: Context<Trade>, collateral_amount: u64) -> Result<()> {
+        handle_sell(ctx, collateral_amount)
+    }
+}
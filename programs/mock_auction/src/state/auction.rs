@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{AUCTION_SEED, BPS, PRICE_SCALE};
+
+/// Sells collateral at a discount to the oracle price and buys it back at the oracle price
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct Auction {
+    pub bump: [u8; 1],
+    pub owner: Pubkey,
+    pub oracle: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub underlying_vault: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub discount_bps: u64,
+}
+
+impl Auction {
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [
+            AUCTION_SEED.as_bytes(),
+            self.oracle.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+
+    pub fn discounted_price(&self, oracle_price: u64) -> u64 {
+        (oracle_price as u128 * (BPS - self.discount_bps) as u128 / BPS as u128) as u64
+    }
+
+    pub fn collateral_for(&self, underlying_amount: u64, oracle_price: u64) -> u64 {
+        let price = self.discounted_price(oracle_price);
+        (underlying_amount as u128 * PRICE_SCALE as u128 / price as u128) as u64
+    }
+}
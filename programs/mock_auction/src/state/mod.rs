@@ -0,0 +1,5 @@
+pub mod auction;
+pub mod oracle;
+
+pub use auction::*;
+pub use oracle::*;
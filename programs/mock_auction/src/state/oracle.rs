@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PRICE_SCALE;
+
+/// Price feed of the collateral in underlying tokens
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct PriceOracle {
+    pub owner: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub underlying_mint: Pubkey,
+    /// underlying per collateral token, scaled by PRICE_SCALE
+    pub price: u64,
+    pub updated_at: i64,
+}
+
+impl PriceOracle {
+    pub fn collateral_value(&self, collateral: u64) -> u64 {
+        (collateral as u128 * self.price as u128 / PRICE_SCALE as u128) as u64
+    }
+}
@@ -0,0 +1,6 @@
+pub const MARKET_SEED: &str = "market";
+pub const RESERVE_SEED: &str = "reserve";
+pub const COLLATERAL_SEED: &str = "collateral";
+
+/// Exchange rate is the amount of underlying per collateral token scaled by this value
+pub const EXCHANGE_RATE_SCALE: u64 = 1_000_000_000;
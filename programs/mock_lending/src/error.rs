@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer has no access")]
+    AccessDenied,
+
+    #[msg("Exchange rate cannot be lower than the initial one")]
+    InvalidExchangeRate,
+
+    #[msg("Amount is zero")]
+    ZeroAmount,
+
+    #[msg("Not enough liquidity in the reserve")]
+    InsufficientLiquidity,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::{COLLATERAL_SEED, EXCHANGE_RATE_SCALE, MARKET_SEED, RESERVE_SEED};
+use crate::state::Market;
+
+#[derive(Accounts)]
+pub struct InitMarket<'info> {
+    #[account(
+        init, 
+        seeds = [MARKET_SEED.as_bytes(), underlying_mint.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        space = 8 + Market::INIT_SPACE
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init, 
+        seeds = [RESERVE_SEED.as_bytes(), market.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        token::mint = underlying_mint, 
+        token::authority = market,
+    )]
+    pub reserve: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init, 
+        seeds = [COLLATERAL_SEED.as_bytes(), market.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        mint::decimals = underlying_mint.decimals, 
+        mint::authority = market,
+    )]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account()]
+    pub underlying_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_init_market(ctx: Context<InitMarket>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    market.bump = [ctx.bumps.market];
+    market.owner = ctx.accounts.signer.key();
+    market.underlying_mint = ctx.accounts.underlying_mint.key();
+    market.reserve = ctx.accounts.reserve.key();
+    market.collateral_mint = ctx.accounts.collateral_mint.key();
+    market.exchange_rate = EXCHANGE_RATE_SCALE;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod init_market;
+pub mod redeem;
+pub mod set_exchange_rate;
+pub mod supply;
+
+pub use init_market::*;
+pub use redeem::*;
+pub use set_exchange_rate::*;
+pub use supply::*;
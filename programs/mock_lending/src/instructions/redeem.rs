@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::ErrorCode;
+use crate::state::Market;
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(has_one = reserve, has_one = collateral_mint)]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub reserve: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = collateral_mint)]
+    pub user_collateral_account: Box<Account<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_redeem(ctx: Context<Redeem>, collateral_amount: u64) -> Result<()> {
+    let amount = ctx.accounts.market.collateral_to_underlying(collateral_amount);
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    if ctx.accounts.reserve.amount < amount {
+        return Err(ErrorCode::InsufficientLiquidity.into());
+    }
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.collateral_mint.to_account_info(),
+                from: ctx.accounts.user_collateral_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        collateral_amount,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.market.to_account_info(),
+            },
+            &[&ctx.accounts.market.seeds()],
+        ),
+        amount,
+    )
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EXCHANGE_RATE_SCALE;
+use crate::error::ErrorCode;
+use crate::state::Market;
+
+/// Simulates interest accrual, the owner is expected to top up the reserve accordingly
+#[derive(Accounts)]
+pub struct SetExchangeRate<'info> {
+    #[account(mut, has_one = owner @ErrorCode::AccessDenied)]
+    pub market: Account<'info, Market>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_exchange_rate(ctx: Context<SetExchangeRate>, exchange_rate: u64) -> Result<()> {
+    if exchange_rate < EXCHANGE_RATE_SCALE {
+        return Err(ErrorCode::InvalidExchangeRate.into());
+    }
+
+    ctx.accounts.market.exchange_rate = exchange_rate;
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::error::ErrorCode;
+use crate::state::Market;
+
+#[derive(Accounts)]
+pub struct Supply<'info> {
+    #[account(has_one = reserve, has_one = collateral_mint)]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub reserve: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = collateral_mint)]
+    pub user_collateral_account: Box<Account<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_supply(ctx: Context<Supply>, amount: u64) -> Result<()> {
+    let collateral = ctx.accounts.market.underlying_to_collateral(amount);
+    if collateral == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.reserve.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collateral_mint.to_account_info(),
+                to: ctx.accounts.user_collateral_account.to_account_info(),
+                authority: ctx.accounts.market.to_account_info(),
+            },
+            &[&ctx.accounts.market.seeds()],
+        ),
+        collateral,
+    )
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod instructions;
+pub mod state;
+pub mod error;
+
+pub use constants::*;
+pub use instructions::*;
+pub use state::*;
+
+declare_id!("JBXMQFk9MwPZRi4pXzegKN6hMeen4V16AmTJjdVsQTtN");
+
+#[program]
+pub mod mock_lending {
+    use super::*;
+
+    pub fn init_market(ctx: Context<InitMarket>) -> Result<()> {
+        handle_init_market(ctx)
+    }
+
+    pub fn set_exchange_rate(ctx: Context<SetExchangeRate>, exchange_rate: u64) -> Result<()> {
+        handle_set_exchange_rate(ctx, exchange_rate)
+    }
+
+    pub fn supply(ctx: Context<Supply>, amount: u64) -> Result<()> {
+        handle_supply(ctx, amount)
+    }
+
+    pub fn redeem(ctx: Context<Redeem>, collateral_amount: u64) -> Result<()> {
+        handle_redeem(ctx, collateral_amount)
+    }
+}
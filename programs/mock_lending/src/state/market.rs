@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EXCHANGE_RATE_SCALE, MARKET_SEED};
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct Market {
+    pub bump: [u8; 1],
+    pub owner: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub reserve: Pubkey,
+    pub collateral_mint: Pubkey,
+    /// underlying per collateral token, scaled by EXCHANGE_RATE_SCALE
+    pub exchange_rate: u64,
+}
+
+impl Market {
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [
+            MARKET_SEED.as_bytes(),
+            self.underlying_mint.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+
+    pub fn collateral_to_underlying(&self, collateral: u64) -> u64 {
+        (collateral as u128 * self.exchange_rate as u128 / EXCHANGE_RATE_SCALE as u128) as u64
+    }
+
+    pub fn underlying_to_collateral(&self, amount: u64) -> u64 {
+        (amount as u128 * EXCHANGE_RATE_SCALE as u128 / self.exchange_rate as u128) as u64
+    }
+}
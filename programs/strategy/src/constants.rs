@@ -1,8 +1,24 @@
+use anchor_lang::prelude::*;
+
 pub const VAULT_SEED: &str = "vault";
 pub const SHARES_SEED: &str = "shares";
 pub const UNDERLYING_SEED: &str = "underlying";
 pub const CONFIG_SEED: &str = "config";
+pub const PENDING_MANAGER_SEED: &str = "pending_manager";
+pub const PENDING_FEE_MANAGER_SEED: &str = "pending_fee_manager";
 
 pub const FEE_BPS: u64 = 10_000;
 
 pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Current layout version of the strategy accounts
+pub const STRATEGY_VERSION: u8 = 1;
+
+/// Number of open positions a liquidation strategy can hold
+pub const MAX_LIQUIDATION_POSITIONS: usize = 8;
+
+/// Number of instalments an investor strategy can be repaid with
+pub const MAX_INSTALMENTS: usize = 12;
+
+/// tokenized_vault program, allocator strategies deposit into its vaults
+pub const TOKENIZED_VAULT_PROGRAM_ID: Pubkey = pubkey!("8eDcyX8Z8yZXBQsuatwxDC1qzGbuUbP7wGERDBQoPmBH");
@@ -40,4 +40,37 @@ pub enum ErrorCode {
 
     #[msg("Loss is too high")]
     LossTooHigh,
+
+    #[msg("Account is already migrated")]
+    AlreadyMigrated,
+
+    #[msg("NAV attestation is too old")]
+    StaleNav,
+
+    #[msg("Invalid NAV attestation")]
+    InvalidAttestation,
+
+    #[msg("Attestation nonce was already used")]
+    InvalidNonce,
+
+    #[msg("All position slots are taken")]
+    NoFreePositionSlot,
+
+    #[msg("Position not found")]
+    PositionNotFound,
+
+    #[msg("Amount exceeds the allocation")]
+    AllocationExceeded,
+
+    #[msg("Investee has defaulted")]
+    StrategyDefaulted,
+
+    #[msg("Repayment exceeds the outstanding instalments")]
+    RepaymentTooHigh,
+
+    #[msg("Strategy is shut down")]
+    StrategyShutdown,
+
+    #[msg("Strategy is not shut down")]
+    StrategyNotShutdown,
 }
@@ -31,4 +31,138 @@ pub struct StrategyWithdrawEvent {
 pub struct SetPerformanceFeeEvent {
     pub account_key: Pubkey,
     pub fee: u64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct StrategyMigratedEvent {
+    pub account_key: Pubkey,
+    pub version: u8,
+    pub old_len: u64,
+    pub new_len: u64,
+}
+
+#[event]
+pub struct NavUpdatedEvent {
+    pub account_key: Pubkey,
+    pub nav: u64,
+    pub timestamp: i64,
+    pub nonce: u64,
+    pub total_assets: u64,
+}
+
+#[event]
+pub struct RWAConfigUpdatedEvent {
+    pub account_key: Pubkey,
+    pub attestor: Pubkey,
+    pub max_nav_staleness: i64,
+    pub redemption_window_start: i64,
+    pub redemption_period: i64,
+    pub redemption_window_duration: i64,
+}
+
+#[event]
+pub struct LiquidationPositionOpenedEvent {
+    pub account_key: Pubkey,
+    pub auction: Pubkey,
+    pub index: u8,
+    pub collateral_amount: u64,
+    pub cost: u64,
+}
+
+#[event]
+pub struct LiquidationPositionClosedEvent {
+    pub account_key: Pubkey,
+    pub auction: Pubkey,
+    pub collateral_amount: u64,
+    pub cost: u64,
+    pub proceeds: u64,
+}
+
+#[event]
+pub struct InstalmentOverdueEvent {
+    pub account_key: Pubkey,
+    pub index: u8,
+    pub due_date: i64,
+    pub outstanding: u64,
+}
+
+#[event]
+pub struct InvestorDefaultedEvent {
+    pub account_key: Pubkey,
+    pub index: u8,
+    pub outstanding: u64,
+    pub outstanding_principal: u64,
+}
+
+#[event]
+pub struct InstalmentRepaidEvent {
+    pub account_key: Pubkey,
+    pub amount: u64,
+    pub total_repaid: u64,
+}
+#[event]
+pub struct StrategyShutdownEvent {
+    pub account_key: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct StrategyEmergencyWithdrawEvent {
+    pub account_key: Pubkey,
+    pub requested: u64,
+    /// what actually reached the strategy token account
+    pub freed: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct StrategyTendEvent {
+    pub account_key: Pubkey,
+    pub total_assets: u64,
+}
+
+#[event]
+pub struct RoleManagementUpdatedEvent {
+    pub account_key: Pubkey,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct ManagerProposedEvent {
+    pub account_key: Pubkey,
+    pub manager: Pubkey,
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct ManagementAcceptedEvent {
+    pub account_key: Pubkey,
+    pub previous_manager: Pubkey,
+    pub manager: Pubkey,
+}
+
+#[event]
+pub struct ManagerProposalCancelledEvent {
+    pub account_key: Pubkey,
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct FeeManagerProposedEvent {
+    pub account_key: Pubkey,
+    pub fee_manager: Pubkey,
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct FeeManagementAcceptedEvent {
+    pub account_key: Pubkey,
+    pub previous_fee_manager: Pubkey,
+    pub fee_manager: Pubkey,
+}
+
+#[event]
+pub struct FeeManagerProposalCancelledEvent {
+    pub account_key: Pubkey,
+    pub proposed: Pubkey,
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::utils::unchecked_strategy::deserialize_as;
+
+/// Permissionless, flags overdue instalments and defaults of an investor strategy
+#[derive(Accounts)]
+pub struct CheckInstalments<'info> {
+    /// CHECK: must be an investor strategy, checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+}
+
+pub fn handle_check_instalments(ctx: Context<CheckInstalments>) -> Result<()> {
+    let mut strategy = deserialize_as::<InvestorStrategy>(&ctx.accounts.strategy)?;
+
+    strategy.check_instalments(Clock::get()?.unix_timestamp);
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+}
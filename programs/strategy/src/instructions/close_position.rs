@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::TokenAccount,
+};
+
+use crate::constants::UNDERLYING_SEED;
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    /// CHECK: must be a liquidation strategy, checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
+    pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[cfg(not(feature = "liquidation"))]
+pub fn handle_close_position<'info>(_ctx: Context<'_, '_, '_, 'info, ClosePosition<'info>>, _index: u8) -> Result<()> {
+    Err(crate::error::ErrorCode::NotSupported.into())
+}
+
+#[cfg(feature = "liquidation")]
+pub fn handle_close_position<'info>(ctx: Context<'_, '_, '_, 'info, ClosePosition<'info>>, index: u8) -> Result<()> {
+    use access_control::state::Role;
+    use crate::state::*;
+    use crate::utils::{roles, unchecked_strategy::deserialize_as};
+
+    let mut strategy = deserialize_as::<LiquidationStrategy>(&ctx.accounts.strategy)?;
+
+    roles::check_role(
+        &strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyManager,
+        ctx.remaining_accounts,
+    )?;
+
+    strategy.close_position(
+        ctx.accounts.strategy.to_account_info(),
+        &mut ctx.accounts.underlying_token_account,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        index as usize,
+    )?;
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+}
@@ -1,11 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::Token,
-    token_interface::TokenAccount,
-};
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::error::ErrorCode;
-use crate::utils::token::transfer;
 use crate::utils::unchecked_strategy::UncheckedStrategy;
 use crate::constants::UNDERLYING_SEED;
 
@@ -15,16 +11,12 @@ pub struct Deposit<'info> {
     #[account(mut)]
     pub strategy: UncheckedAccount<'info>,
 
-    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
+    /// the vault transfers the assets before calling deposit
+    #[account(seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
     pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(mut)]
-    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
-
     #[account(constraint = signer.key() == strategy.vault() @ErrorCode::AccessDenied)]
     pub signer: Signer<'info>,
-
-    pub token_program: Program<'info, Token>,
 }
 
 pub fn handle_deposit<'info>(
@@ -33,6 +25,10 @@ pub fn handle_deposit<'info>(
 ) -> Result<()> {
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
+    if strategy.is_shutdown() {
+        return Err(ErrorCode::StrategyShutdown.into());
+    }
+
     let max_deposit = strategy.available_deposit();
 
     if amount > max_deposit {
@@ -40,13 +36,5 @@ pub fn handle_deposit<'info>(
     }
 
     strategy.deposit(amount)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
-
-    transfer(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.vault_token_account.to_account_info(), 
-        ctx.accounts.underlying_token_account.to_account_info(), 
-        ctx.accounts.signer.to_account_info(), 
-        amount
-    )
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
 }
\ No newline at end of file
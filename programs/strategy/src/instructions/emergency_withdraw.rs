@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::TokenAccount,
+};
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::UNDERLYING_SEED;
+use crate::error::ErrorCode;
+use crate::events::StrategyEmergencyWithdrawEvent;
+use crate::instructions::FreeFunds;
+use crate::utils::unchecked_strategy::UncheckedStrategy;
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    /// CHECK: can be any strategy
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
+    pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::EmergencyAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub access_control: Program<'info, AccessControl>,
+}
+
+/// Frees deployed funds back to the strategy token account of a shut down strategy,
+/// remaining accounts are the ones the strategy type expects in `free_funds`.
+/// Strategy types whose funds can't be recalled on chain are rejected.
+pub fn handle_emergency_withdraw<'info>(ctx: Context<'_, '_, '_, 'info, EmergencyWithdraw<'info>>, amount: u64) -> Result<()> {
+    let mut strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    if !strategy.is_shutdown() {
+        return Err(ErrorCode::StrategyNotShutdown.into());
+    }
+
+    if !strategy.can_free_funds() {
+        return Err(ErrorCode::NotSupported.into());
+    }
+
+    let pre_balance = ctx.accounts.underlying_token_account.amount;
+
+    let accounts = FreeFunds {
+        strategy: ctx.accounts.strategy.clone(),
+        underlying_token_account: ctx.accounts.underlying_token_account.clone(),
+        signer: ctx.accounts.signer.clone(),
+        token_program: ctx.accounts.token_program.clone(),
+    };
+
+    strategy.free_funds(&accounts, ctx.remaining_accounts, amount)?;
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+
+    ctx.accounts.underlying_token_account.reload()?;
+
+    emit!(StrategyEmergencyWithdrawEvent {
+        account_key: ctx.accounts.strategy.key(),
+        requested: amount,
+        freed: ctx.accounts.underlying_token_account.amount.saturating_sub(pre_balance),
+        authority: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -3,9 +3,10 @@ use anchor_spl::{
     token::Token,
     token_interface::TokenAccount,
 };
+use access_control::state::Role;
 
 use crate::utils::unchecked_strategy::UncheckedStrategy;
-use crate::error::ErrorCode;
+use crate::utils::roles;
 use crate::constants::UNDERLYING_SEED;
 
 #[derive(Accounts)]
@@ -29,9 +30,13 @@ pub struct FreeFunds<'info> {
 pub fn handle_free_funds<'info>(ctx: Context<'_, '_, '_, 'info, FreeFunds<'info>>, amount: u64) -> Result<()> {
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
-    if *ctx.accounts.signer.key != strategy.manager() {
-        return Err(ErrorCode::AccessDenied.into());
-    }
+    roles::check_role(
+        &*strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyManager,
+        ctx.remaining_accounts,
+    )?;
 
     strategy.free_funds(&ctx.accounts, &ctx.remaining_accounts, amount)?;
     strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
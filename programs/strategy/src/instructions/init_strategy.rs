@@ -12,7 +12,6 @@ use access_control::{
 
 use crate::constants::{CONFIG_SEED, UNDERLYING_SEED};
 use crate::state::*;
-use crate::error::ErrorCode;
 
 #[derive(Accounts)]
 #[instruction(strategy_type: StrategyType)]
@@ -75,14 +74,32 @@ pub struct InitStrategy<'info> {
 pub fn handle_init_strategy(ctx: Context<InitStrategy>, strategy_type: StrategyType, config: Vec<u8>) -> Result<()> {
     match strategy_type {
         StrategyType::Simple => {
-            return init_strategy_internal::<SimpleStrategy>(ctx, config)
+            init_strategy_internal::<SimpleStrategy>(ctx, config)
         }
         StrategyType::TradeFintech => {
-            return init_strategy_internal::<TradeFintechStrategy>(ctx, config)
+            init_strategy_internal::<TradeFintechStrategy>(ctx, config)
         }
-        _ => {
-            return Err(ErrorCode::InvalidStrategyData.into())
+        #[cfg(feature = "lending")]
+        StrategyType::Lending => {
+            init_strategy_internal::<LendingStrategy>(ctx, config)
         }
+        StrategyType::RWA => {
+            init_strategy_internal::<RWAStrategy>(ctx, config)
+        }
+        #[cfg(feature = "liquidation")]
+        StrategyType::Liquidation => {
+            init_strategy_internal::<LiquidationStrategy>(ctx, config)
+        }
+        StrategyType::Investor => {
+            init_strategy_internal::<InvestorStrategy>(ctx, config)
+        }
+        StrategyType::Allocator => {
+            init_strategy_internal::<AllocatorStrategy>(ctx, config)
+        }
+        #[cfg(not(feature = "lending"))]
+        StrategyType::Lending => Err(crate::error::ErrorCode::NotSupported.into()),
+        #[cfg(not(feature = "liquidation"))]
+        StrategyType::Liquidation => Err(crate::error::ErrorCode::NotSupported.into()),
     }
 }
 
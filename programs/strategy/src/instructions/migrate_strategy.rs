@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::STRATEGY_VERSION;
+use crate::error::ErrorCode;
+use crate::events::StrategyMigratedEvent;
+use crate::utils::unchecked_strategy::UncheckedStrategy;
+
+#[derive(Accounts)]
+pub struct MigrateStrategy<'info> {
+    /// CHECK: can be any strategy, the layout is resolved by discriminator and size
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::StrategiesManager.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub access_control: Program<'info, AccessControl>,
+}
+
+pub fn handle_migrate_strategy(ctx: Context<MigrateStrategy>) -> Result<()> {
+    let mut strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    if strategy.version() >= STRATEGY_VERSION {
+        return Err(ErrorCode::AlreadyMigrated.into());
+    }
+
+    let strategy_info = ctx.accounts.strategy.to_account_info();
+    let old_len = strategy_info.data_len();
+    let new_len = strategy.strategy_type().space();
+
+    if new_len > old_len {
+        account_utils::realloc(
+            &strategy_info,
+            &ctx.accounts.signer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_len,
+        )?;
+    }
+
+    strategy.set_version(STRATEGY_VERSION);
+    strategy.save_changes(&mut &mut strategy_info.try_borrow_mut_data()?[8..])?;
+
+    emit!(
+        StrategyMigratedEvent {
+            account_key: strategy_info.key(),
+            version: STRATEGY_VERSION,
+            old_len: old_len as u64,
+            new_len: new_len as u64,
+        }
+    );
+
+    Ok(())
+}
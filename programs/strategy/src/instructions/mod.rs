@@ -1,25 +1,47 @@
+pub mod check_instalments;
+pub mod close_position;
 pub mod deploy_funds;
 pub mod deposit;
+pub mod emergency_withdraw;
 pub mod free_funds;
 pub mod initialize;
 pub mod init_strategy;
+pub mod migrate_strategy;
+pub mod record_repayment;
 pub mod report;
 pub mod report_loss;
 pub mod report_profit;
 pub mod setters;
+pub mod shutdown_strategy;
+pub mod tend;
+pub mod transfer_fee_management;
 pub mod transfer_management;
+pub mod triggers;
+pub mod update_nav;
+pub mod views;
 pub mod withdraw;
 pub mod withdraw_fee;
 
+pub use check_instalments::*;
+pub use close_position::*;
 pub use deploy_funds::*;
 pub use deposit::*;
+pub use emergency_withdraw::*;
 pub use free_funds::*;
 pub use initialize::*;
 pub use init_strategy::*;
+pub use migrate_strategy::*;
+pub use record_repayment::*;
 pub use report::*;
 pub use report_loss::*;
 pub use report_profit::*;
 pub use setters::*;
+pub use shutdown_strategy::*;
+pub use tend::*;
+pub use transfer_fee_management::*;
 pub use transfer_management::*;
+pub use triggers::*;
+pub use update_nav::*;
+pub use views::*;
 pub use withdraw::*;
 pub use withdraw_fee::*;
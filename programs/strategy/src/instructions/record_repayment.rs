@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::TokenAccount,
+};
+use access_control::state::Role;
+
+use crate::constants::UNDERLYING_SEED;
+use crate::utils::roles;
+use crate::state::*;
+use crate::utils::{token, unchecked_strategy::deserialize_as};
+
+#[derive(Accounts)]
+pub struct RecordRepayment<'info> {
+    /// CHECK: must be an investor strategy, checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
+    pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// funds repaid by the investee, owned by the signer
+    #[account(mut)]
+    pub source_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_record_repayment(ctx: Context<RecordRepayment>, amount: u64) -> Result<()> {
+    let mut strategy = deserialize_as::<InvestorStrategy>(&ctx.accounts.strategy)?;
+
+    roles::check_role(
+        &strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyManager,
+        ctx.remaining_accounts,
+    )?;
+
+    token::transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.source_token_account.to_account_info(),
+        ctx.accounts.underlying_token_account.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        amount,
+    )?;
+
+    strategy.record_repayment(amount)?;
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+}
@@ -3,9 +3,10 @@ use anchor_spl::{
     token::Token,
     token_interface::TokenAccount,
 };
+use access_control::state::Role;
 
 use crate::utils::unchecked_strategy::UncheckedStrategy;
-use crate::error::ErrorCode;
+use crate::utils::roles;
 use crate::constants::UNDERLYING_SEED;
 
 #[derive(Accounts)]
@@ -25,9 +26,13 @@ pub struct ReportLoss<'info> {
 pub fn handle_report_loss<'info>(ctx: Context<'_, '_, '_, 'info, ReportLoss<'info>>, loss: u64) -> Result<()> {
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
-    if ctx.accounts.signer.key() != strategy.manager() {
-        return Err(ErrorCode::AccessDenied.into());
-    }
+    roles::check_role(
+        &*strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyKeeper,
+        ctx.remaining_accounts,
+    )?;
 
     strategy.report_loss(&ctx.accounts, &ctx.remaining_accounts, loss)?;
     strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use access_control::state::Role;
 
-use crate::events::SetPerformanceFeeEvent;
-use crate::utils::unchecked_strategy::UncheckedStrategy;
 use crate::error::ErrorCode;
+use crate::events::{RoleManagementUpdatedEvent, SetPerformanceFeeEvent};
+use crate::state::{RWAParams, RWAStrategy, StrategyDataAccount};
+use crate::utils::unchecked_strategy::{deserialize_as, UncheckedStrategy};
+use crate::utils::roles;
 
 #[derive(Accounts)]
 pub struct SetPerformanceFee<'info> {
@@ -14,9 +17,18 @@ pub struct SetPerformanceFee<'info> {
 }
 
 #[derive(Accounts)]
-pub struct SetFeeManager<'info> {
+pub struct SetRoleManagement<'info> {
     /// CHECK: can by any strategy
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
     #[account(mut)]
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRWAConfig<'info> {
+    /// CHECK: must be an RWA strategy, checked in the handler
+    #[account(mut, owner = crate::ID)]
     pub strategy: UncheckedAccount<'info>,
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -25,9 +37,13 @@ pub struct SetFeeManager<'info> {
 pub fn handle_set_performance_fee<'info>(ctx: Context<SetPerformanceFee<'info>>, new_fee: u64) -> Result<()> {
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
-    if *ctx.accounts.signer.key != strategy.manager() {
-        return Err(ErrorCode::AccessDenied.into());
-    }
+    roles::check_role(
+        &*strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyFeeAdmin,
+        ctx.remaining_accounts,
+    )?;
     
     let fee_data = &mut strategy.fee_data();
     fee_data.set_performance_fee(new_fee)?;
@@ -40,14 +56,36 @@ pub fn handle_set_performance_fee<'info>(ctx: Context<SetPerformanceFee<'info>>,
     strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
 }
 
-pub fn handle_set_fee_manager<'info>(ctx: Context<SetFeeManager<'info>>, recipient: Pubkey) -> Result<()> {
+pub fn handle_set_rwa_config<'info>(ctx: Context<SetRWAConfig<'info>>, params: RWAParams) -> Result<()> {
+    let mut strategy = deserialize_as::<RWAStrategy>(&ctx.accounts.strategy)?;
+
+    roles::check_role(
+        &strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyManager,
+        ctx.remaining_accounts,
+    )?;
+
+    strategy.set_params(params)?;
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+}
+
+/// Lets holders of the strategy scoped keeper, manager and fee admin roles act besides the manager
+pub fn handle_set_role_management<'info>(ctx: Context<SetRoleManagement<'info>>, enabled: bool) -> Result<()> {
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
     if *ctx.accounts.signer.key != strategy.manager() {
         return Err(ErrorCode::AccessDenied.into());
     }
 
-    let fee_data = &mut strategy.fee_data();
-    fee_data.set_fee_manager(recipient)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
-}
\ No newline at end of file
+    strategy.set_uses_roles(enabled);
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+
+    emit!(RoleManagementUpdatedEvent {
+        account_key: ctx.accounts.strategy.key(),
+        enabled,
+    });
+
+    Ok(())
+}
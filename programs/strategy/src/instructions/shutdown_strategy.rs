@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::error::ErrorCode;
+use crate::events::StrategyShutdownEvent;
+use crate::utils::unchecked_strategy::UncheckedStrategy;
+
+#[derive(Accounts)]
+pub struct ShutdownStrategy<'info> {
+    /// CHECK: can be any strategy
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::EmergencyAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+}
+
+/// Stops the strategy from taking new deposits, it can't be undone
+pub fn handle_shutdown_strategy(ctx: Context<ShutdownStrategy>) -> Result<()> {
+    let mut strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    if strategy.is_shutdown() {
+        return Err(ErrorCode::StrategyShutdown.into());
+    }
+
+    strategy.shutdown();
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+
+    emit!(StrategyShutdownEvent {
+        account_key: ctx.accounts.strategy.key(),
+        authority: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
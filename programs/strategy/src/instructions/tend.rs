@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::TokenAccount,
+};
+use access_control::state::Role;
+
+use crate::utils::unchecked_strategy::UncheckedStrategy;
+use crate::utils::roles;
+use crate::constants::UNDERLYING_SEED;
+use crate::events::StrategyTendEvent;
+use crate::instructions::DeployFunds;
+
+#[derive(Accounts)]
+pub struct Tend<'info> {
+    /// CHECK: can by any strategy
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
+    pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Tend<'info> {
+    /// For strategies whose tend puts idle funds to work
+    pub fn deploy_funds_accounts(&self) -> DeployFunds<'info> {
+        DeployFunds {
+            strategy: self.strategy.clone(),
+            underlying_token_account: self.underlying_token_account.clone(),
+            signer: self.signer.clone(),
+            token_program: self.token_program.clone(),
+        }
+    }
+}
+
+pub fn handle_tend<'info>(ctx: Context<'_, '_, '_, 'info, Tend<'info>>) -> Result<()> {
+    let mut strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    roles::check_role(
+        &*strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyKeeper,
+        ctx.remaining_accounts,
+    )?;
+
+    strategy.tend(ctx.accounts, ctx.remaining_accounts)?;
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+
+    emit!(StrategyTendEvent {
+        account_key: ctx.accounts.strategy.key(),
+        total_assets: strategy.total_assets(),
+    });
+
+    Ok(())
+}
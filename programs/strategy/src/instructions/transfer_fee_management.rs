@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use access_control::state::Role;
+
+use crate::constants::PENDING_FEE_MANAGER_SEED;
+use crate::events::{FeeManagementAcceptedEvent, FeeManagerProposalCancelledEvent, FeeManagerProposedEvent};
+use crate::state::PendingTransfer;
+use crate::utils::roles;
+use crate::utils::unchecked_strategy::UncheckedStrategy;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ProposeFeeManager<'info> {
+    /// CHECK: can by any strategy
+    #[account(owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [PENDING_FEE_MANAGER_SEED.as_bytes(), strategy.key().as_ref()],
+        bump,
+        payer = signer,
+        space = PendingTransfer::LEN
+    )]
+    pub pending_fee_manager: Account<'info, PendingTransfer>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptFeeManagement<'info> {
+    /// CHECK: can by any strategy
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_FEE_MANAGER_SEED.as_bytes(), strategy.key().as_ref()],
+        bump,
+        close = payer,
+        constraint = pending_fee_manager.proposed == signer.key() @ ErrorCode::AccessDenied
+    )]
+    pub pending_fee_manager: Account<'info, PendingTransfer>,
+
+    /// CHECK: gets the rent of the proposal back
+    #[account(mut, address = pending_fee_manager.payer)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelFeeManagerProposal<'info> {
+    /// CHECK: can by any strategy
+    #[account(owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_FEE_MANAGER_SEED.as_bytes(), strategy.key().as_ref()],
+        bump,
+        close = payer
+    )]
+    pub pending_fee_manager: Account<'info, PendingTransfer>,
+
+    /// CHECK: gets the rent of the proposal back
+    #[account(mut, address = pending_fee_manager.payer)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Fees keep going to the current fee manager until the proposed key accepts
+pub fn handle_propose_fee_manager<'info>(ctx: Context<ProposeFeeManager<'info>>, recipient: Pubkey) -> Result<()> {
+    let mut strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    roles::check_role(
+        &*strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyFeeAdmin,
+        ctx.remaining_accounts,
+    )?;
+
+    let pending_fee_manager = &mut ctx.accounts.pending_fee_manager;
+    pending_fee_manager.strategy = ctx.accounts.strategy.key();
+    pending_fee_manager.proposed = recipient;
+    pending_fee_manager.payer = ctx.accounts.signer.key();
+
+    emit!(FeeManagerProposedEvent {
+        account_key: ctx.accounts.strategy.key(),
+        fee_manager: strategy.fee_data().fee_manager(),
+        proposed: recipient,
+    });
+
+    Ok(())
+}
+
+pub fn handle_accept_fee_management<'info>(ctx: Context<AcceptFeeManagement<'info>>) -> Result<()> {
+    let mut strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    let fee_data = strategy.fee_data();
+    let previous_fee_manager = fee_data.fee_manager();
+    fee_data.set_fee_manager(ctx.accounts.signer.key())?;
+
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+
+    emit!(FeeManagementAcceptedEvent {
+        account_key: ctx.accounts.strategy.key(),
+        previous_fee_manager,
+        fee_manager: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+pub fn handle_cancel_fee_manager_proposal<'info>(ctx: Context<CancelFeeManagerProposal<'info>>) -> Result<()> {
+    let strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    roles::check_role(
+        &*strategy,
+        &ctx.accounts.strategy.key(),
+        ctx.accounts.signer.key,
+        Role::StrategyFeeAdmin,
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(FeeManagerProposalCancelledEvent {
+        account_key: ctx.accounts.strategy.key(),
+        proposed: ctx.accounts.pending_fee_manager.proposed,
+    });
+
+    Ok(())
+}
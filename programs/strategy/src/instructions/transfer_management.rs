@@ -1,25 +1,124 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::PENDING_MANAGER_SEED;
+use crate::events::{ManagementAcceptedEvent, ManagerProposalCancelledEvent, ManagerProposedEvent};
+use crate::state::PendingTransfer;
 use crate::utils::unchecked_strategy::UncheckedStrategy;
 use crate::error::ErrorCode;
 
 #[derive(Accounts)]
-pub struct TransferManagement<'info> {
+pub struct ProposeManager<'info> {
     /// CHECK: can by any strategy
-    #[account(mut)]
+    #[account(owner = crate::ID)]
     pub strategy: UncheckedAccount<'info>,
-    
+
+    #[account(
+        init,
+        seeds = [PENDING_MANAGER_SEED.as_bytes(), strategy.key().as_ref()],
+        bump,
+        payer = signer,
+        space = PendingTransfer::LEN
+    )]
+    pub pending_manager: Account<'info, PendingTransfer>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptManagement<'info> {
+    /// CHECK: can by any strategy
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_MANAGER_SEED.as_bytes(), strategy.key().as_ref()],
+        bump,
+        close = payer,
+        constraint = pending_manager.proposed == signer.key() @ ErrorCode::AccessDenied
+    )]
+    pub pending_manager: Account<'info, PendingTransfer>,
+
+    /// CHECK: gets the rent of the proposal back
+    #[account(mut, address = pending_manager.payer)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelManagerProposal<'info> {
+    /// CHECK: can by any strategy
+    #[account(owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_MANAGER_SEED.as_bytes(), strategy.key().as_ref()],
+        bump,
+        close = payer
+    )]
+    pub pending_manager: Account<'info, PendingTransfer>,
+
+    /// CHECK: gets the rent of the proposal back
+    #[account(mut, address = pending_manager.payer)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
 }
 
-pub fn handle_transfer_management<'info>(ctx: Context<TransferManagement<'info>>, new_manager: Pubkey) -> Result<()> {
+/// The manager stays in place until the proposed key accepts, a pending proposal has to be cancelled before a new one
+pub fn handle_propose_manager<'info>(ctx: Context<ProposeManager<'info>>, new_manager: Pubkey) -> Result<()> {
+    let strategy = ctx.accounts.strategy.from_unchecked()?;
+
+    if *ctx.accounts.signer.key != strategy.manager() {
+        return Err(ErrorCode::AccessDenied.into());
+    }
+
+    let pending_manager = &mut ctx.accounts.pending_manager;
+    pending_manager.strategy = ctx.accounts.strategy.key();
+    pending_manager.proposed = new_manager;
+    pending_manager.payer = ctx.accounts.signer.key();
+
+    emit!(ManagerProposedEvent {
+        account_key: ctx.accounts.strategy.key(),
+        manager: strategy.manager(),
+        proposed: new_manager,
+    });
+
+    Ok(())
+}
+
+pub fn handle_accept_management<'info>(ctx: Context<AcceptManagement<'info>>) -> Result<()> {
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
+    let previous_manager = strategy.manager();
+
+    strategy.set_manager(ctx.accounts.signer.key())?;
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+
+    emit!(ManagementAcceptedEvent {
+        account_key: ctx.accounts.strategy.key(),
+        previous_manager,
+        manager: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+pub fn handle_cancel_manager_proposal<'info>(ctx: Context<CancelManagerProposal<'info>>) -> Result<()> {
+    let strategy = ctx.accounts.strategy.from_unchecked()?;
 
     if *ctx.accounts.signer.key != strategy.manager() {
         return Err(ErrorCode::AccessDenied.into());
     }
 
-    strategy.set_manager(new_manager)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
-}
\ No newline at end of file
+    emit!(ManagerProposalCancelledEvent {
+        account_key: ctx.accounts.strategy.key(),
+        proposed: ctx.accounts.pending_manager.proposed,
+    });
+
+    Ok(())
+}
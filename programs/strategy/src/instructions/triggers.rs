@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::UNDERLYING_SEED;
+use crate::state::KeeperTrigger;
+use crate::utils::unchecked_strategy::UncheckedStrategy;
+
+/// Read-only keeper queries, results are passed back through return data
+#[derive(Accounts)]
+pub struct TriggerView<'info> {
+    /// CHECK: can by any strategy
+    #[account(owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
+    pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub fn handle_tend_trigger(ctx: Context<TriggerView>) -> Result<KeeperTrigger> {
+    let strategy = ctx.accounts.strategy.from_unchecked()?;
+    Ok(strategy.tend_trigger(ctx.accounts.underlying_token_account.amount, Clock::get()?.unix_timestamp))
+}
+
+pub fn handle_report_trigger(ctx: Context<TriggerView>) -> Result<KeeperTrigger> {
+    let strategy = ctx.accounts.strategy.from_unchecked()?;
+    Ok(strategy.report_trigger(ctx.accounts.underlying_token_account.amount, Clock::get()?.unix_timestamp))
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::{
+    token::Token,
+    token_interface::TokenAccount,
+};
+
+use crate::constants::UNDERLYING_SEED;
+use crate::instructions::Report;
+use crate::state::*;
+use crate::utils::{ed25519, unchecked_strategy::deserialize_as};
+
+#[derive(Accounts)]
+pub struct UpdateNav<'info> {
+    /// CHECK: must be an RWA strategy, checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
+    pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: instructions sysvar, used to find the ed25519 signature check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// anyone can relay a signed attestation
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// The transaction must contain an ed25519 program instruction right before this one
+/// verifying the attestor signature over the borsh serialized NavAttestation.
+pub fn handle_update_nav(ctx: Context<UpdateNav>, attestation: NavAttestation) -> Result<()> {
+    let mut strategy = deserialize_as::<RWAStrategy>(&ctx.accounts.strategy)?;
+
+    ed25519::verify_previous_ed25519_ix(
+        &ctx.accounts.instructions,
+        &strategy.attestor,
+        &attestation.try_to_vec()?,
+    )?;
+
+    strategy.update_nav(&attestation, Clock::get()?.unix_timestamp)?;
+    strategy.report(
+        &Report {
+            strategy: ctx.accounts.strategy.clone(),
+            underlying_token_account: ctx.accounts.underlying_token_account.clone(),
+            signer: ctx.accounts.signer.clone(),
+            token_program: ctx.accounts.token_program.clone(),
+        },
+        &[],
+    )?;
+    strategy.emit_nav_updated();
+
+    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::utils::unchecked_strategy::UncheckedStrategy;
+
+/// Read-only queries of the strategy interface, results are passed back through return data
+#[derive(Accounts)]
+pub struct StrategyView<'info> {
+    /// CHECK: can by any strategy
+    #[account(owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+}
+
+pub fn handle_total_assets(ctx: Context<StrategyView>) -> Result<u64> {
+    Ok(ctx.accounts.strategy.from_unchecked()?.total_assets())
+}
+
+pub fn handle_max_withdraw(ctx: Context<StrategyView>) -> Result<u64> {
+    Ok(ctx.accounts.strategy.from_unchecked()?.available_withdraw())
+}
+
+pub fn handle_max_deposit(ctx: Context<StrategyView>) -> Result<u64> {
+    Ok(ctx.accounts.strategy.from_unchecked()?.available_deposit())
+}
+
+pub fn handle_strategy_vault(ctx: Context<StrategyView>) -> Result<Pubkey> {
+    Ok(ctx.accounts.strategy.from_unchecked()?.vault())
+}
@@ -21,6 +21,18 @@ pub struct RegAcc<'info> {
     pub simple_strategy: Account<'info, SimpleStrategy>,
     #[account()]
     pub tf_strategy: Account<'info, TradeFintechStrategy>,
+    #[cfg(feature = "lending")]
+    #[account()]
+    pub lending_strategy: Account<'info, LendingStrategy>,
+    #[account()]
+    pub rwa_strategy: Account<'info, RWAStrategy>,
+    #[cfg(feature = "liquidation")]
+    #[account()]
+    pub liquidation_strategy: Account<'info, LiquidationStrategy>,
+    #[account()]
+    pub investor_strategy: Account<'info, InvestorStrategy>,
+    #[account()]
+    pub allocator_strategy: Account<'info, AllocatorStrategy>,
 }
 
 #[program]
@@ -40,6 +52,10 @@ pub mod strategy {
         handle_init_strategy(ctx, strategy_type, config)
     }
 
+    pub fn migrate_strategy(ctx: Context<MigrateStrategy>) -> Result<()> {
+        handle_migrate_strategy(ctx)
+    }
+
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         handle_deposit(ctx, amount)
     }
@@ -60,23 +76,103 @@ pub mod strategy {
         handle_report_loss(ctx, loss)
     }
 
-    pub fn transfer_management(ctx: Context<TransferManagement>, new_admin: Pubkey) -> Result<()> {
-        handle_transfer_management(ctx, new_admin)
+    pub fn propose_manager(ctx: Context<ProposeManager>, new_manager: Pubkey) -> Result<()> {
+        handle_propose_manager(ctx, new_manager)
+    }
+
+    pub fn accept_management(ctx: Context<AcceptManagement>) -> Result<()> {
+        handle_accept_management(ctx)
+    }
+
+    pub fn cancel_manager_proposal(ctx: Context<CancelManagerProposal>) -> Result<()> {
+        handle_cancel_manager_proposal(ctx)
     }
 
     pub fn set_performance_fee(ctx: Context<SetPerformanceFee>, fee: u64) -> Result<()> {
         handle_set_performance_fee(ctx, fee)
     }
 
-    pub fn set_fee_manager(ctx: Context<SetFeeManager>, recipient: Pubkey) -> Result<()> {
-        handle_set_fee_manager(ctx, recipient)
+    pub fn propose_fee_manager(ctx: Context<ProposeFeeManager>, recipient: Pubkey) -> Result<()> {
+        handle_propose_fee_manager(ctx, recipient)
+    }
+
+    pub fn accept_fee_management(ctx: Context<AcceptFeeManagement>) -> Result<()> {
+        handle_accept_fee_management(ctx)
+    }
+
+    pub fn cancel_fee_manager_proposal(ctx: Context<CancelFeeManagerProposal>) -> Result<()> {
+        handle_cancel_fee_manager_proposal(ctx)
     }
 
     pub fn withdraw_fee<'info>(ctx:  Context<'_, '_, '_, 'info, WithdrawFee<'info>>, amount: u64) -> Result<()> {
         handle_withdraw_fee(ctx, amount)
     }
 
+    pub fn set_role_management(ctx: Context<SetRoleManagement>, enabled: bool) -> Result<()> {
+        handle_set_role_management(ctx, enabled)
+    }
+
+    pub fn set_rwa_config(ctx: Context<SetRWAConfig>, params: RWAParams) -> Result<()> {
+        handle_set_rwa_config(ctx, params)
+    }
+
+    pub fn update_nav(ctx: Context<UpdateNav>, attestation: NavAttestation) -> Result<()> {
+        handle_update_nav(ctx, attestation)
+    }
+
     pub fn deploy_funds<'info>(ctx:  Context<'_, '_, '_, 'info, DeployFunds<'info>>, amount: u64) -> Result<()> {
         handle_deploy_funds(ctx, amount)
     }
+
+    pub fn free_funds<'info>(ctx:  Context<'_, '_, '_, 'info, FreeFunds<'info>>, amount: u64) -> Result<()> {
+        handle_free_funds(ctx, amount)
+    }
+
+    pub fn tend<'info>(ctx:  Context<'_, '_, '_, 'info, Tend<'info>>) -> Result<()> {
+        handle_tend(ctx)
+    }
+
+    pub fn shutdown_strategy(ctx: Context<ShutdownStrategy>) -> Result<()> {
+        handle_shutdown_strategy(ctx)
+    }
+
+    pub fn emergency_withdraw<'info>(ctx:  Context<'_, '_, '_, 'info, EmergencyWithdraw<'info>>, amount: u64) -> Result<()> {
+        handle_emergency_withdraw(ctx, amount)
+    }
+
+    pub fn close_position<'info>(ctx:  Context<'_, '_, '_, 'info, ClosePosition<'info>>, index: u8) -> Result<()> {
+        handle_close_position(ctx, index)
+    }
+
+    pub fn record_repayment(ctx: Context<RecordRepayment>, amount: u64) -> Result<()> {
+        handle_record_repayment(ctx, amount)
+    }
+
+    pub fn check_instalments(ctx: Context<CheckInstalments>) -> Result<()> {
+        handle_check_instalments(ctx)
+    }
+
+    pub fn total_assets(ctx: Context<StrategyView>) -> Result<u64> {
+        handle_total_assets(ctx)
+    }
+
+    pub fn max_withdraw(ctx: Context<StrategyView>) -> Result<u64> {
+        handle_max_withdraw(ctx)
+    }
+
+    pub fn max_deposit(ctx: Context<StrategyView>) -> Result<u64> {
+        handle_max_deposit(ctx)
+    }
+
+    pub fn strategy_vault(ctx: Context<StrategyView>) -> Result<Pubkey> {
+        handle_strategy_vault(ctx)
+    }
+
+    pub fn tend_trigger(ctx: Context<TriggerView>) -> Result<KeeperTrigger> {
+        handle_tend_trigger(ctx)
+    }
+
+    pub fn report_trigger(ctx: Context<TriggerView>) -> Result<KeeperTrigger> {
+        handle_report_trigger(ctx)
+    }
 }
\ No newline at end of file
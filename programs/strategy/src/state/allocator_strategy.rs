@@ -0,0 +1,399 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::Mint;
+
+use super::base_strategy::*;
+use super::StrategyType;
+use super::keeper_trigger::*;
+use super::fee_data::*;
+
+use crate::constants::{STRATEGY_VERSION, TOKENIZED_VAULT_PROGRAM_ID};
+use crate::error::ErrorCode;
+use crate::events::{StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent};
+use crate::utils::vault_cpi::{self, VaultAccounts};
+use crate::instructions::{Report, ReportProfit, ReportLoss, DeployFunds, FreeFunds, Tend};
+
+/// Deposits into another tokenized vault and holds its shares ("vault of vaults").
+/// deploy_funds, free_funds and report expect the next remaining accounts:
+/// [0] - target vault
+/// [1] - target vault token account
+/// [2] - target vault shares mint
+/// [3] - strategy shares account
+/// [4] - access control program
+/// [5] - tokenized vault program
+/// [6..] - forwarded to the target vault deposit (e.g. whitelist roles)
+///
+/// The runtime does not allow tokenized_vault -> strategy -> tokenized_vault calls,
+/// so funds are freed by the manager through `free_funds` ahead of vault withdrawals.
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct AllocatorStrategy {
+    /// Bump to identify PDA
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+
+    /// vault
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub undelying_decimals: u8,
+
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+
+    pub target_vault: Pubkey,
+    pub shares_token_acc: Pubkey,
+
+    /// value of the target vault shares and how much of it could be redeemed, as of the last update
+    pub position_value: u64,
+    pub withdrawable: u64,
+
+    pub fee_data: FeeData,
+
+    pub version: u8,
+    /// set by shutdown_strategy, no new deposits are accepted
+    pub is_shutdown: bool,
+    /// authorization through strategy scoped access_control roles besides the manager
+    pub uses_roles: bool,
+    pub reserved_flags: [u8; 6],
+    pub reserved: [u64; 7],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllocatorStrategyConfig {
+    pub deposit_limit: u64,
+    pub performance_fee: u64,
+    pub fee_manager: Pubkey,
+    pub target_vault: Pubkey,
+    pub shares_token_acc: Pubkey,
+}
+
+impl AllocatorStrategy {
+    fn validate_remaining(&self, remaining: &[AccountInfo]) -> Result<()> {
+        if remaining.len() < 6 {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        if remaining[0].key() != self.target_vault
+            || remaining[3].key() != self.shares_token_acc
+            || remaining[5].key() != TOKENIZED_VAULT_PROGRAM_ID
+        {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        Ok(())
+    }
+
+    fn shares_balance(&self, account: &AccountInfo) -> Result<u64> {
+        let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        if token_account.owner != self.key() {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+        Ok(token_account.amount)
+    }
+
+    fn vault_accounts<'a, 'info>(
+        remaining: &'a [AccountInfo<'info>],
+        strategy: AccountInfo<'info>,
+        underlying_token_account: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+    ) -> VaultAccounts<'a, 'info> {
+        VaultAccounts {
+            vault: &remaining[0],
+            vault_token_account: &remaining[1],
+            shares_mint: &remaining[2],
+            user_shares_account: &remaining[3],
+            user_token_account: underlying_token_account,
+            user: strategy,
+            token_program,
+            vault_program: &remaining[5],
+        }
+    }
+
+    /// Refreshes the position value and the redeemable part of it from the target vault
+    fn update_position(&mut self, remaining: &[AccountInfo]) -> Result<()> {
+        let shares = self.shares_balance(&remaining[3])?;
+
+        self.position_value = vault_cpi::view("preview_redeem", &remaining[0], &remaining[5], shares)?;
+        self.withdrawable = vault_cpi::view("max_withdraw", &remaining[0], &remaining[5], shares)?;
+        Ok(())
+    }
+}
+
+impl StrategyManagement for AllocatorStrategy {
+    fn manager(&self) -> Pubkey {
+        self.manager
+    }
+
+    fn set_manager(&mut self, manager: Pubkey) -> Result<()> {
+        self.manager = manager;
+        Ok(())
+    }
+
+    fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn shutdown(&mut self) {
+        self.is_shutdown = true;
+    }
+
+    fn uses_roles(&self) -> bool {
+        self.uses_roles
+    }
+
+    fn set_uses_roles(&mut self, enabled: bool) {
+        self.uses_roles = enabled;
+    }
+}
+
+impl Strategy for AllocatorStrategy {
+    fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_assets += amount;
+
+        emit!(
+            StrategyDepositEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.total_assets -= amount;
+
+        emit!(
+            StrategyWithdrawEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw_fees(&mut self, amount: u64) -> Result<()> {
+        self.fee_data.fee_balance -= amount;
+        Ok(())
+    }
+
+    /// gains come from the target vault and are picked up by report
+    fn report_profit<'info>(&mut self, _accounts: &ReportProfit<'info>, _remaining: &[AccountInfo<'info>], _profit: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn report_loss<'info>(&mut self, _accounts: &ReportLoss<'info>, _remaining: &[AccountInfo<'info>], _loss: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn harvest_and_report<'info>(&mut self, accounts: &Report<'info>, remaining: &[AccountInfo<'info>]) -> Result<u64> {
+        if accounts.underlying_token_account.key() != self.underlying_token_acc {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+        self.validate_remaining(remaining)?;
+        self.update_position(remaining)?;
+
+        let new_total_assets = accounts.underlying_token_account.amount + self.position_value;
+        Ok(new_total_assets)
+    }
+
+    fn deploy_funds<'info>(&mut self, accounts: &DeployFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        self.validate_remaining(remaining)?;
+
+        if accounts.underlying_token_account.amount < amount {
+            return Err(ErrorCode::InsufficientFunds.into());
+        }
+
+        vault_cpi::deposit(
+            &Self::vault_accounts(
+                remaining,
+                accounts.strategy.to_account_info(),
+                accounts.underlying_token_account.to_account_info(),
+                accounts.token_program.to_account_info(),
+            ),
+            &remaining[4],
+            &remaining[6..],
+            amount,
+            &self.seeds(),
+        )?;
+
+        self.update_position(remaining)
+    }
+
+    fn can_free_funds(&self) -> bool {
+        true
+    }
+
+    /// redeems enough target vault shares to cover the amount, capped by the shares held
+    fn free_funds<'info>(&mut self, accounts: &FreeFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        self.validate_remaining(remaining)?;
+        self.update_position(remaining)?;
+
+        let shares = self.shares_balance(&remaining[3])?;
+        if shares == 0 || self.position_value == 0 {
+            return Ok(());
+        }
+
+        let to_redeem = (amount as u128 * shares as u128).div_ceil(self.position_value as u128) as u64;
+        let to_redeem = std::cmp::min(to_redeem, shares);
+
+        vault_cpi::redeem(
+            &Self::vault_accounts(
+                remaining,
+                accounts.strategy.to_account_info(),
+                accounts.underlying_token_account.to_account_info(),
+                accounts.token_program.to_account_info(),
+            ),
+            to_redeem,
+            &self.seeds(),
+        )?;
+
+        self.update_position(remaining)
+    }
+
+    /// supplies idle funds, except the fees, to the target vault
+    fn tend<'info>(&mut self, accounts: &Tend<'info>, remaining: &[AccountInfo<'info>]) -> Result<()> {
+        let idle = accounts.underlying_token_account.amount.saturating_sub(self.fee_data.fee_balance);
+        if idle == 0 {
+            return Ok(());
+        }
+
+        self.deploy_funds(&accounts.deploy_funds_accounts(), remaining, idle)
+    }
+
+    fn tend_trigger(&self, idle: u64, _timestamp: i64) -> KeeperTrigger {
+        if self.is_shutdown || idle <= self.fee_data.fee_balance {
+            return KeeperTrigger::none();
+        }
+        KeeperTrigger::act(TriggerReason::IdleFunds)
+    }
+
+    fn set_total_assets(&mut self, total_assets: u64) {
+        self.total_assets = total_assets;
+    }
+}
+
+impl StretegyGetters for AllocatorStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Allocator
+    }
+
+    fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    fn total_assets(&self) -> u64 {
+        self.total_assets
+    }
+
+    fn available_deposit(&self) -> u64 {
+        if self.is_shutdown {
+            return 0;
+        }
+        self.deposit_limit.saturating_sub(self.total_assets)
+    }
+
+    /// idle funds plus what the target vault allows to redeem
+    fn available_withdraw(&self) -> u64 {
+        let idle = self.total_assets.saturating_sub(self.position_value);
+        std::cmp::min(self.total_assets, idle + self.withdrawable)
+    }
+
+    fn token_account(&self) -> Pubkey {
+        self.underlying_token_acc
+    }
+
+    fn underlying_mint(&self) -> Pubkey {
+        self.underlying_mint
+    }
+
+    fn fee_data(&mut self) -> &mut FeeData {
+        &mut self.fee_data
+    }
+}
+
+impl StrategyInit for AllocatorStrategy {
+    fn init(
+        &mut self,
+        bump: u8,
+        index: u64,
+        vault: Pubkey,
+        underlying_mint: &InterfaceAccount<Mint>,
+        underlying_token_acc: Pubkey,
+        config_bytes: Vec<u8>
+    ) -> Result<()> {
+        let config = AllocatorStrategyConfig::try_from_slice(&config_bytes)
+        .map_err(|_| ErrorCode::InvalidStrategyConfig)?;
+
+        if config.target_vault == vault {
+            return Err(ErrorCode::InvalidStrategyConfig.into());
+        }
+
+        self.bump = [bump];
+        self.index_bytes = index.to_le_bytes();
+        self.vault = vault;
+        self.underlying_mint = underlying_mint.key();
+        self.undelying_decimals = underlying_mint.decimals;
+        self.underlying_token_acc = underlying_token_acc;
+        self.deposit_limit = config.deposit_limit;
+        self.target_vault = config.target_vault;
+        self.shares_token_acc = config.shares_token_acc;
+        self.total_assets = 0;
+        self.position_value = 0;
+        self.withdrawable = 0;
+
+        self.fee_data = FeeData {
+            fee_manager: config.fee_manager,
+            performance_fee: config.performance_fee,
+            fee_balance: 0,
+        };
+        self.version = STRATEGY_VERSION;
+
+        emit!(
+            StrategyInitEvent
+            {
+                account_key: self.key(),
+                strategy_type: String::from("allocator"),
+                vault: self.vault,
+                underlying_mint: self.underlying_mint,
+                underlying_token_acc: self.underlying_token_acc,
+                undelying_decimals: self.undelying_decimals,
+                deposit_limit: self.deposit_limit,
+                deposit_period_ends: 0,
+                lock_period_ends: 0,
+            });
+
+        Ok(())
+    }
+}
+
+impl StrategyDataAccount for AllocatorStrategy {
+    fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        self.try_to_vec().map_err(|_| ErrorCode::SerializationError.into()).and_then(|vec| {
+            writer.write_all(&vec).map_err(|_| ErrorCode::SerializationError.into())
+        })
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn seeds(&self) -> [&[u8]; 3] {
+        [
+            self.vault.as_ref(),
+            self.index_bytes.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
@@ -3,10 +3,13 @@ use anchor_spl::token_interface::Mint;
 
 use super::*;
 use crate::constants::FEE_BPS;
-use crate::instructions::{Report, ReportProfit, ReportLoss, DeployFunds, FreeFunds};
+use crate::instructions::{Report, ReportProfit, ReportLoss, DeployFunds, FreeFunds, Tend};
 
 pub trait StrategyDataAccount {
     fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()>;
+    /// Layout version of the account, legacy accounts are read as version 0
+    fn version(&self) -> u8;
+    fn set_version(&mut self, version: u8);
     fn seeds(&self) -> [&[u8]; 3];
     fn key(&self) -> Pubkey {
         let seeds = self.seeds();
@@ -29,6 +32,11 @@ pub trait StrategyInit {
 pub trait StrategyManagement {
     fn manager(&self) -> Pubkey;
     fn set_manager(&mut self, manager: Pubkey) -> Result<()>;
+    /// A shut down strategy accepts no deposits, funds can still be withdrawn
+    fn is_shutdown(&self) -> bool;
+    fn shutdown(&mut self);
+    fn uses_roles(&self) -> bool;
+    fn set_uses_roles(&mut self, enabled: bool);
 }
 
 pub trait StretegyGetters {
@@ -60,6 +68,26 @@ pub trait Strategy:
 
     fn report_profit<'info>(&mut self, accounts: &ReportProfit<'info>, remaining: &[AccountInfo<'info>], profit: u64) -> Result<()>;
     fn report_loss<'info>(&mut self, accounts: &ReportLoss<'info>, remaining: &[AccountInfo<'info>], loss: u64) -> Result<()>;
+
+    /// True if `free_funds` recalls deployed funds on chain, emergency withdrawals are rejected otherwise
+    fn can_free_funds(&self) -> bool {
+        false
+    }
+
+    /// Work between reports, like compounding rewards or rebalancing, nothing by default
+    fn tend<'info>(&mut self, _accounts: &Tend<'info>, _remaining: &[AccountInfo<'info>]) -> Result<()> {
+        Ok(())
+    }
+
+    /// `idle` is the balance of the underlying token account
+    fn tend_trigger(&self, _idle: u64, _timestamp: i64) -> KeeperTrigger {
+        KeeperTrigger::none()
+    }
+
+    fn report_trigger(&self, _idle: u64, _timestamp: i64) -> KeeperTrigger {
+        KeeperTrigger::none()
+    }
+
     fn report<'info>(&mut self, accounts: &Report<'info>, remaining: &[AccountInfo<'info>]) -> Result<()> {
         let old_total_assets = self.total_assets();
         let new_total_assets = self.harvest_and_report(accounts, remaining)?;
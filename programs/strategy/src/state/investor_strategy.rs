@@ -0,0 +1,451 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use super::base_strategy::*;
+use super::StrategyType;
+use super::keeper_trigger::*;
+use super::fee_data::*;
+
+use crate::constants::{MAX_INSTALMENTS, STRATEGY_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{
+    InstalmentOverdueEvent, InstalmentRepaidEvent, InvestorDefaultedEvent,
+    StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent
+};
+use crate::utils::token;
+use crate::instructions::{Report, ReportProfit, ReportLoss, DeployFunds, FreeFunds};
+
+/// Deploys a fixed allocation to an investee which repays it by instalments.
+/// Outstanding principal is valued at cost until the investee defaults,
+/// after that it is written off on the next report.
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct InvestorStrategy {
+    /// Bump to identify PDA
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+
+    /// vault
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub undelying_decimals: u8,
+
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+
+    /// token account of the investee
+    pub investee: Pubkey,
+    pub allocation: u64,
+    pub total_invested: u64,
+    pub total_repaid: u64,
+
+    /// seconds after a due date before an unpaid instalment is a default
+    pub grace_period: i64,
+    pub is_defaulted: bool,
+    pub instalments_count: u8,
+    pub instalments: [Instalment; MAX_INSTALMENTS],
+
+    pub fee_data: FeeData,
+
+    pub version: u8,
+    /// set by shutdown_strategy, no new deposits are accepted
+    pub is_shutdown: bool,
+    /// authorization through strategy scoped access_control roles besides the manager
+    pub uses_roles: bool,
+    pub reserved_flags: [u8; 6],
+    pub reserved: [u64; 7],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, InitSpace)]
+pub struct Instalment {
+    pub due_date: i64,
+    pub amount: u64,
+    pub repaid: u64,
+    pub is_overdue: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InstalmentConfig {
+    pub due_date: i64,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InvestorStrategyConfig {
+    pub deposit_limit: u64,
+    pub performance_fee: u64,
+    pub fee_manager: Pubkey,
+    pub investee: Pubkey,
+    pub allocation: u64,
+    pub grace_period: i64,
+    pub instalments: Vec<InstalmentConfig>,
+}
+
+impl Instalment {
+    pub fn outstanding(&self) -> u64 {
+        self.amount - self.repaid
+    }
+}
+
+impl InvestorStrategy {
+    fn active_instalments(&mut self) -> &mut [Instalment] {
+        &mut self.instalments[..self.instalments_count as usize]
+    }
+
+    /// Principal still held by the investee, written off once defaulted
+    pub fn outstanding_principal(&self) -> u64 {
+        if self.is_defaulted {
+            return 0;
+        }
+        self.total_invested.saturating_sub(self.total_repaid)
+    }
+
+    /// Flags overdue instalments and the default once the grace period has passed
+    pub fn check_instalments(&mut self, timestamp: i64) {
+        let key = self.key();
+        let grace_period = self.grace_period;
+        let mut defaulted = None;
+
+        for (index, instalment) in self.active_instalments().iter_mut().enumerate() {
+            if instalment.outstanding() == 0 || timestamp <= instalment.due_date {
+                continue;
+            }
+
+            if !instalment.is_overdue {
+                instalment.is_overdue = true;
+                emit!(
+                    InstalmentOverdueEvent {
+                        account_key: key,
+                        index: index as u8,
+                        due_date: instalment.due_date,
+                        outstanding: instalment.outstanding(),
+                    }
+                );
+            }
+
+            if defaulted.is_none() && timestamp > instalment.due_date + grace_period {
+                defaulted = Some((index, instalment.outstanding()));
+            }
+        }
+
+        if let Some((index, outstanding)) = defaulted {
+            if !self.is_defaulted {
+                self.is_defaulted = true;
+                emit!(
+                    InvestorDefaultedEvent {
+                        account_key: key,
+                        index: index as u8,
+                        outstanding,
+                        outstanding_principal: self.total_invested.saturating_sub(self.total_repaid),
+                    }
+                );
+            }
+        }
+    }
+
+    /// Applies a repayment to the instalments, oldest first
+    pub fn record_repayment(&mut self, amount: u64) -> Result<()> {
+        let mut remaining = amount;
+
+        for instalment in self.active_instalments().iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+
+            let repaid = std::cmp::min(remaining, instalment.outstanding());
+            instalment.repaid += repaid;
+            remaining -= repaid;
+        }
+
+        if remaining > 0 {
+            return Err(ErrorCode::RepaymentTooHigh.into());
+        }
+
+        self.total_repaid += amount;
+
+        emit!(
+            InstalmentRepaidEvent {
+                account_key: self.key(),
+                amount,
+                total_repaid: self.total_repaid,
+            }
+        );
+
+        Ok(())
+    }
+}
+
+impl StrategyManagement for InvestorStrategy {
+    fn manager(&self) -> Pubkey {
+        self.manager
+    }
+
+    fn set_manager(&mut self, manager: Pubkey) -> Result<()> {
+        self.manager = manager;
+        Ok(())
+    }
+
+    fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn shutdown(&mut self) {
+        self.is_shutdown = true;
+    }
+
+    fn uses_roles(&self) -> bool {
+        self.uses_roles
+    }
+
+    fn set_uses_roles(&mut self, enabled: bool) {
+        self.uses_roles = enabled;
+    }
+}
+
+impl Strategy for InvestorStrategy {
+    fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_assets += amount;
+
+        emit!(
+            StrategyDepositEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.total_assets -= amount;
+
+        emit!(
+            StrategyWithdrawEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw_fees(&mut self, amount: u64) -> Result<()> {
+        self.fee_data.fee_balance -= amount;
+        Ok(())
+    }
+
+    /// repayments are recorded with record_repayment and picked up by report
+    fn report_profit<'info>(&mut self, _accounts: &ReportProfit<'info>, _remaining: &[AccountInfo<'info>], _profit: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn report_loss<'info>(&mut self, _accounts: &ReportLoss<'info>, _remaining: &[AccountInfo<'info>], _loss: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn harvest_and_report<'info>(&mut self, accounts: &Report<'info>, _remaining: &[AccountInfo<'info>]) -> Result<u64> {
+        if accounts.underlying_token_account.key() != self.underlying_token_acc {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        self.check_instalments(Clock::get()?.unix_timestamp);
+
+        let new_total_assets = accounts.underlying_token_account.amount + self.outstanding_principal();
+        Ok(new_total_assets)
+    }
+
+    /// accounts should be the next:
+    /// [0] - investee token account
+    fn deploy_funds<'info>(&mut self, accounts: &DeployFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        if remaining.is_empty() || remaining[0].key() != self.investee {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        if self.is_defaulted {
+            return Err(ErrorCode::StrategyDefaulted.into());
+        }
+
+        if self.total_invested + amount > self.allocation {
+            return Err(ErrorCode::AllocationExceeded.into());
+        }
+
+        token::transfer_with_signer(
+            accounts.token_program.to_account_info(),
+            accounts.underlying_token_account.to_account_info(),
+            remaining[0].to_account_info(),
+            accounts.strategy.to_account_info(),
+            amount,
+            &self.seeds()
+        )?;
+
+        self.total_invested += amount;
+        Ok(())
+    }
+
+    /// deployed funds come back only through repayments
+    fn free_funds<'info>(&mut self, _accounts: &FreeFunds<'info>, _remaining: &[AccountInfo<'info>], _amount: u64) -> Result<()> {
+        Ok(())
+    }
+
+    fn report_trigger(&self, _idle: u64, timestamp: i64) -> KeeperTrigger {
+        let mut trigger = KeeperTrigger::none();
+
+        for instalment in self.instalments[..self.instalments_count as usize].iter() {
+            if instalment.outstanding() == 0 || timestamp <= instalment.due_date {
+                continue;
+            }
+
+            if !self.is_defaulted && timestamp > instalment.due_date + self.grace_period {
+                return KeeperTrigger::act(TriggerReason::InstalmentDefaulted);
+            }
+
+            if !instalment.is_overdue {
+                trigger = KeeperTrigger::act(TriggerReason::InstalmentOverdue);
+            }
+        }
+
+        trigger
+    }
+
+    fn set_total_assets(&mut self, total_assets: u64) {
+        self.total_assets = total_assets;
+    }
+}
+
+impl StretegyGetters for InvestorStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Investor
+    }
+
+    fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    fn total_assets(&self) -> u64 {
+        self.total_assets
+    }
+
+    fn available_deposit(&self) -> u64 {
+        if self.is_shutdown {
+            return 0;
+        }
+        self.deposit_limit.saturating_sub(self.total_assets)
+    }
+
+    fn available_withdraw(&self) -> u64 {
+        self.total_assets.saturating_sub(self.outstanding_principal())
+    }
+
+    fn token_account(&self) -> Pubkey {
+        self.underlying_token_acc
+    }
+
+    fn underlying_mint(&self) -> Pubkey {
+        self.underlying_mint
+    }
+
+    fn fee_data(&mut self) -> &mut FeeData {
+        &mut self.fee_data
+    }
+}
+
+impl StrategyInit for InvestorStrategy {
+    fn init(
+        &mut self,
+        bump: u8,
+        index: u64,
+        vault: Pubkey,
+        underlying_mint: &InterfaceAccount<Mint>,
+        underlying_token_acc: Pubkey,
+        config_bytes: Vec<u8>
+    ) -> Result<()> {
+        let config = InvestorStrategyConfig::try_from_slice(&config_bytes)
+        .map_err(|_| ErrorCode::InvalidStrategyConfig)?;
+
+        if config.instalments.is_empty()
+            || config.instalments.len() > MAX_INSTALMENTS
+            || config.grace_period < 0
+            || config.instalments.windows(2).any(|w| w[0].due_date >= w[1].due_date)
+        {
+            return Err(ErrorCode::InvalidStrategyConfig.into());
+        }
+
+        self.bump = [bump];
+        self.index_bytes = index.to_le_bytes();
+        self.vault = vault;
+        self.underlying_mint = underlying_mint.key();
+        self.undelying_decimals = underlying_mint.decimals;
+        self.underlying_token_acc = underlying_token_acc;
+        self.deposit_limit = config.deposit_limit;
+        self.investee = config.investee;
+        self.allocation = config.allocation;
+        self.grace_period = config.grace_period;
+        self.total_assets = 0;
+        self.total_invested = 0;
+        self.total_repaid = 0;
+        self.is_defaulted = false;
+
+        self.instalments_count = config.instalments.len() as u8;
+        for (instalment, cfg) in self.instalments.iter_mut().zip(config.instalments.iter()) {
+            *instalment = Instalment {
+                due_date: cfg.due_date,
+                amount: cfg.amount,
+                repaid: 0,
+                is_overdue: false,
+            };
+        }
+
+        self.fee_data = FeeData {
+            fee_manager: config.fee_manager,
+            performance_fee: config.performance_fee,
+            fee_balance: 0,
+        };
+        self.version = STRATEGY_VERSION;
+
+        emit!(
+            StrategyInitEvent
+            {
+                account_key: self.key(),
+                strategy_type: String::from("investor"),
+                vault: self.vault,
+                underlying_mint: self.underlying_mint,
+                underlying_token_acc: self.underlying_token_acc,
+                undelying_decimals: self.undelying_decimals,
+                deposit_limit: self.deposit_limit,
+                deposit_period_ends: 0,
+                lock_period_ends: 0,
+            });
+
+        Ok(())
+    }
+}
+
+impl StrategyDataAccount for InvestorStrategy {
+    fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        self.try_to_vec().map_err(|_| ErrorCode::SerializationError.into()).and_then(|vec| {
+            writer.write_all(&vec).map_err(|_| ErrorCode::SerializationError.into())
+        })
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn seeds(&self) -> [&[u8]; 3] {
+        [
+            self.vault.as_ref(),
+            self.index_bytes.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
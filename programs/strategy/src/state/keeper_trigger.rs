@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum TriggerReason {
+    None,
+    /// idle funds can be put to work
+    IdleFunds,
+    /// the lock period is over and the investment can be reported
+    LockPeriodEnded,
+    /// a fresh NAV differs from the reported value
+    NavChanged,
+    /// an instalment is past its due date and not flagged yet
+    InstalmentOverdue,
+    /// the grace period of an instalment has passed and the default is not recorded yet
+    InstalmentDefaulted,
+}
+
+/// Returned by the trigger views, tells a keeper whether to act and why
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct KeeperTrigger {
+    pub should_act: bool,
+    pub reason: TriggerReason,
+}
+
+impl KeeperTrigger {
+    pub fn none() -> Self {
+        KeeperTrigger {
+            should_act: false,
+            reason: TriggerReason::None,
+        }
+    }
+
+    pub fn act(reason: TriggerReason) -> Self {
+        KeeperTrigger {
+            should_act: true,
+            reason,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use super::fee_data::FeeData;
+use super::simple_strategy::SimpleStrategy;
+use super::trade_fintech_srategy::TradeFintechStrategy;
+
+/// Layouts of strategy accounts created before the version field was introduced.
+/// They are only used to read old accounts, `migrate_strategy` rewrites them in the current layout.
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, InitSpace)]
+pub struct SimpleStrategyV0 {
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub undelying_decimals: u8,
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+    pub fee_data: FeeData,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, InitSpace)]
+pub struct TradeFintechStrategyV0 {
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub undelying_decimals: u8,
+    pub total_invested: u64,
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+    pub deposit_period_ends: i64,
+    pub lock_period_ends: i64,
+    pub fee_data: FeeData,
+}
+
+impl From<SimpleStrategyV0> for SimpleStrategy {
+    fn from(old: SimpleStrategyV0) -> Self {
+        SimpleStrategy {
+            bump: old.bump,
+            index_bytes: old.index_bytes,
+            vault: old.vault,
+            manager: old.manager,
+            underlying_mint: old.underlying_mint,
+            underlying_token_acc: old.underlying_token_acc,
+            undelying_decimals: old.undelying_decimals,
+            total_assets: old.total_assets,
+            deposit_limit: old.deposit_limit,
+            fee_data: old.fee_data,
+            version: 0,
+            is_shutdown: false,
+            uses_roles: false,
+            reserved_flags: [0; 6],
+            reserved: [0; 7],
+        }
+    }
+}
+
+impl From<TradeFintechStrategyV0> for TradeFintechStrategy {
+    fn from(old: TradeFintechStrategyV0) -> Self {
+        TradeFintechStrategy {
+            bump: old.bump,
+            index_bytes: old.index_bytes,
+            vault: old.vault,
+            manager: old.manager,
+            underlying_mint: old.underlying_mint,
+            underlying_token_acc: old.underlying_token_acc,
+            undelying_decimals: old.undelying_decimals,
+            total_invested: old.total_invested,
+            total_assets: old.total_assets,
+            deposit_limit: old.deposit_limit,
+            deposit_period_ends: old.deposit_period_ends,
+            lock_period_ends: old.lock_period_ends,
+            fee_data: old.fee_data,
+            version: 0,
+            is_shutdown: false,
+            uses_roles: false,
+            reserved_flags: [0; 6],
+            reserved: [0; 7],
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::Mint;
+use mock_lending::{
+    cpi::accounts::{Redeem, Supply},
+    state::Market,
+    EXCHANGE_RATE_SCALE,
+};
+
+use super::base_strategy::*;
+use super::StrategyType;
+use super::keeper_trigger::*;
+use super::fee_data::*;
+
+use crate::constants::STRATEGY_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent};
+use crate::instructions::{Report, ReportProfit, ReportLoss, DeployFunds, FreeFunds, Tend};
+
+/// Supplies idle funds to a lending market and earns the market's interest.
+/// deploy_funds, free_funds and report expect the next remaining accounts:
+/// [0] - market
+/// [1] - market reserve
+/// [2] - market collateral mint
+/// [3] - strategy collateral token account
+/// [4] - lending program
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct LendingStrategy {
+    /// Bump to identify PDA
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+
+    /// vault
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub undelying_decimals: u8,
+
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+
+    /// lending market and the strategy owned account holding its collateral tokens
+    pub market: Pubkey,
+    pub collateral_token_acc: Pubkey,
+
+    pub fee_data: FeeData,
+
+    pub version: u8,
+    /// set by shutdown_strategy, no new deposits are accepted
+    pub is_shutdown: bool,
+    /// authorization through strategy scoped access_control roles besides the manager
+    pub uses_roles: bool,
+    pub reserved_flags: [u8; 6],
+
+    /// value of the collateral and how much of it the market liquidity allows to redeem, as of the last update
+    pub position_value: u64,
+    pub withdrawable: u64,
+    pub reserved: [u64; 5],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LendingStrategyConfig {
+    pub deposit_limit: u64,
+    pub performance_fee: u64,
+    pub fee_manager: Pubkey,
+    pub market: Pubkey,
+    pub collateral_token_acc: Pubkey,
+}
+
+impl LendingStrategy {
+    fn validate_remaining(&self, remaining: &[AccountInfo]) -> Result<()> {
+        if remaining.len() < 5 {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        if remaining[0].key() != self.market
+            || remaining[3].key() != self.collateral_token_acc
+            || remaining[4].key() != mock_lending::ID
+        {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        Ok(())
+    }
+
+    /// Total value of the collateral held by the strategy in underlying tokens
+    /// Returns the market and the collateral balance of the strategy
+    fn load_market(&self, remaining: &[AccountInfo]) -> Result<(Market, u64)> {
+        if *remaining[0].owner != mock_lending::ID {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        let market = Market::try_deserialize(&mut &remaining[0].try_borrow_data()?[..])?;
+        let collateral_acc = TokenAccount::try_deserialize(&mut &remaining[3].try_borrow_data()?[..])?;
+
+        if collateral_acc.owner != self.key() || collateral_acc.mint != market.collateral_mint {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        Ok((market, collateral_acc.amount))
+    }
+
+    /// Refreshes the collateral value and the redeemable part of it from the market
+    fn update_position(&mut self, remaining: &[AccountInfo]) -> Result<()> {
+        let (market, collateral) = self.load_market(remaining)?;
+
+        let reserve = TokenAccount::try_deserialize(&mut &remaining[1].try_borrow_data()?[..])?;
+        if remaining[1].key() != market.reserve {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        self.position_value = market.collateral_to_underlying(collateral);
+        self.withdrawable = std::cmp::min(self.position_value, reserve.amount);
+        Ok(())
+    }
+}
+
+impl StrategyManagement for LendingStrategy {
+    fn manager(&self) -> Pubkey {
+        self.manager
+    }
+
+    fn set_manager(&mut self, manager: Pubkey) -> Result<()> {
+        self.manager = manager;
+        Ok(())
+    }
+
+    fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn shutdown(&mut self) {
+        self.is_shutdown = true;
+    }
+
+    fn uses_roles(&self) -> bool {
+        self.uses_roles
+    }
+
+    fn set_uses_roles(&mut self, enabled: bool) {
+        self.uses_roles = enabled;
+    }
+}
+
+impl Strategy for LendingStrategy {
+    fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_assets += amount;
+
+        emit!(
+            StrategyDepositEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.total_assets -= amount;
+
+        emit!(
+            StrategyWithdrawEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw_fees(&mut self, amount: u64) -> Result<()> {
+        self.fee_data.fee_balance -= amount;
+        Ok(())
+    }
+
+    /// profit is accrued by the lending market and picked up by report
+    fn report_profit<'info>(&mut self, _accounts: &ReportProfit<'info>, _remaining: &[AccountInfo<'info>], _profit: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn report_loss<'info>(&mut self, _accounts: &ReportLoss<'info>, _remaining: &[AccountInfo<'info>], _loss: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn harvest_and_report<'info>(&mut self, accounts: &Report<'info>, remaining: &[AccountInfo<'info>]) -> Result<u64> {
+        if accounts.underlying_token_account.key() != self.underlying_token_acc {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+        self.validate_remaining(remaining)?;
+
+        self.update_position(remaining)?;
+
+        let new_total_assets = accounts.underlying_token_account.amount + self.position_value;
+        Ok(new_total_assets)
+    }
+
+    fn deploy_funds<'info>(&mut self, accounts: &DeployFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        self.validate_remaining(remaining)?;
+
+        if accounts.underlying_token_account.amount < amount {
+            return Err(ErrorCode::InsufficientFunds.into());
+        }
+
+        mock_lending::cpi::supply(
+            CpiContext::new_with_signer(
+                remaining[4].clone(),
+                Supply {
+                    market: remaining[0].clone(),
+                    reserve: remaining[1].clone(),
+                    collateral_mint: remaining[2].clone(),
+                    user_token_account: accounts.underlying_token_account.to_account_info(),
+                    user_collateral_account: remaining[3].clone(),
+                    user: accounts.strategy.to_account_info(),
+                    token_program: accounts.token_program.to_account_info(),
+                },
+                &[&self.seeds()],
+            ),
+            amount,
+        )?;
+
+        self.update_position(remaining)
+    }
+
+    fn can_free_funds(&self) -> bool {
+        true
+    }
+
+    /// redeems enough collateral to cover the amount, capped by the collateral balance
+    fn free_funds<'info>(&mut self, accounts: &FreeFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        self.validate_remaining(remaining)?;
+
+        let (market, balance) = self.load_market(remaining)?;
+
+        let rate = market.exchange_rate as u128;
+        let collateral = (amount as u128 * EXCHANGE_RATE_SCALE as u128).div_ceil(rate) as u64;
+        let collateral = std::cmp::min(collateral, balance);
+
+        if collateral == 0 {
+            return self.update_position(remaining);
+        }
+
+        mock_lending::cpi::redeem(
+            CpiContext::new_with_signer(
+                remaining[4].clone(),
+                Redeem {
+                    market: remaining[0].clone(),
+                    reserve: remaining[1].clone(),
+                    collateral_mint: remaining[2].clone(),
+                    user_token_account: accounts.underlying_token_account.to_account_info(),
+                    user_collateral_account: remaining[3].clone(),
+                    user: accounts.strategy.to_account_info(),
+                    token_program: accounts.token_program.to_account_info(),
+                },
+                &[&self.seeds()],
+            ),
+            collateral,
+        )?;
+
+        self.update_position(remaining)
+    }
+
+    /// supplies idle funds, except the fees, to the market
+    fn tend<'info>(&mut self, accounts: &Tend<'info>, remaining: &[AccountInfo<'info>]) -> Result<()> {
+        let idle = accounts.underlying_token_account.amount.saturating_sub(self.fee_data.fee_balance);
+        if idle == 0 {
+            return Ok(());
+        }
+
+        self.deploy_funds(&accounts.deploy_funds_accounts(), remaining, idle)
+    }
+
+    fn tend_trigger(&self, idle: u64, _timestamp: i64) -> KeeperTrigger {
+        if self.is_shutdown || idle <= self.fee_data.fee_balance {
+            return KeeperTrigger::none();
+        }
+        KeeperTrigger::act(TriggerReason::IdleFunds)
+    }
+
+    fn set_total_assets(&mut self, total_assets: u64) {
+        self.total_assets = total_assets;
+    }
+}
+
+impl StretegyGetters for LendingStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Lending
+    }
+
+    fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    fn total_assets(&self) -> u64 {
+        self.total_assets
+    }
+
+    fn available_deposit(&self) -> u64 {
+        if self.is_shutdown {
+            return 0;
+        }
+        self.deposit_limit.saturating_sub(self.total_assets)
+    }
+
+    /// idle funds plus the collateral the market liquidity allows to redeem
+    fn available_withdraw(&self) -> u64 {
+        let idle = self.total_assets.saturating_sub(self.position_value);
+        std::cmp::min(self.total_assets, idle + self.withdrawable)
+    }
+
+    fn token_account(&self) -> Pubkey {
+        self.underlying_token_acc
+    }
+
+    fn underlying_mint(&self) -> Pubkey {
+        self.underlying_mint
+    }
+
+    fn fee_data(&mut self) -> &mut FeeData {
+        &mut self.fee_data
+    }
+}
+
+impl StrategyInit for LendingStrategy {
+    fn init(
+        &mut self,
+        bump: u8,
+        index: u64,
+        vault: Pubkey,
+        underlying_mint: &InterfaceAccount<Mint>,
+        underlying_token_acc: Pubkey,
+        config_bytes: Vec<u8>
+    ) -> Result<()> {
+        let config = LendingStrategyConfig::try_from_slice(&config_bytes)
+        .map_err(|_| ErrorCode::InvalidStrategyConfig)?;
+
+        self.bump = [bump];
+        self.index_bytes = index.to_le_bytes();
+        self.vault = vault;
+        self.underlying_mint = underlying_mint.key();
+        self.undelying_decimals = underlying_mint.decimals;
+        self.underlying_token_acc = underlying_token_acc;
+        self.deposit_limit = config.deposit_limit;
+        self.market = config.market;
+        self.collateral_token_acc = config.collateral_token_acc;
+        self.total_assets = 0;
+        self.position_value = 0;
+        self.withdrawable = 0;
+
+        self.fee_data = FeeData {
+            fee_manager: config.fee_manager,
+            performance_fee: config.performance_fee,
+            fee_balance: 0,
+        };
+        self.version = STRATEGY_VERSION;
+
+        emit!(
+            StrategyInitEvent
+            {
+                account_key: self.key(),
+                strategy_type: String::from("lending"),
+                vault: self.vault,
+                underlying_mint: self.underlying_mint,
+                underlying_token_acc: self.underlying_token_acc,
+                undelying_decimals: self.undelying_decimals,
+                deposit_limit: self.deposit_limit,
+                deposit_period_ends: 0,
+                lock_period_ends: 0,
+            });
+
+        Ok(())
+    }
+}
+
+impl StrategyDataAccount for LendingStrategy {
+    fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        self.try_to_vec().map_err(|_| ErrorCode::SerializationError.into()).and_then(|vec| {
+            writer.write_all(&vec).map_err(|_| ErrorCode::SerializationError.into())
+        })
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn seeds(&self) -> [&[u8]; 3] {
+        [
+            self.vault.as_ref(),
+            self.index_bytes.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
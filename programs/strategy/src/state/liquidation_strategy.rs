@@ -0,0 +1,489 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount as InterfaceTokenAccount};
+use mock_auction::{
+    cpi::accounts::Trade,
+    state::PriceOracle,
+};
+
+use super::base_strategy::*;
+use super::StrategyType;
+use super::fee_data::*;
+
+use crate::constants::{MAX_LIQUIDATION_POSITIONS, STRATEGY_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{
+    LiquidationPositionClosedEvent, LiquidationPositionOpenedEvent,
+    StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent
+};
+use crate::instructions::{Report, ReportProfit, ReportLoss, DeployFunds, FreeFunds};
+
+/// Keeps underlying ready to buy discounted collateral from liquidation auctions.
+/// deploy_funds and close_position expect the next remaining accounts:
+/// [0] - auction
+/// [1] - oracle
+/// [2] - auction underlying vault
+/// [3] - auction collateral vault
+/// [4] - strategy collateral token account
+/// [5] - auction program
+/// report expects the oracle as the only remaining account.
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct LiquidationStrategy {
+    /// Bump to identify PDA
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+
+    /// vault
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub undelying_decimals: u8,
+
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+
+    pub collateral_mint: Pubkey,
+    pub collateral_token_acc: Pubkey,
+    pub oracle: Pubkey,
+
+    /// cost of the open positions
+    pub total_invested: u64,
+    pub positions: [LiquidationPosition; MAX_LIQUIDATION_POSITIONS],
+
+    pub fee_data: FeeData,
+
+    pub version: u8,
+    /// set by shutdown_strategy, no new deposits are accepted
+    pub is_shutdown: bool,
+    /// authorization through strategy scoped access_control roles besides the manager
+    pub uses_roles: bool,
+    pub reserved_flags: [u8; 6],
+
+    /// oracle value of the open positions and the underlying balance, as of the last update
+    pub positions_value: u64,
+    pub idle_balance: u64,
+    pub reserved: [u64; 5],
+}
+
+/// Collateral bought in one auction, the slot is free when collateral_amount is 0
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, InitSpace)]
+pub struct LiquidationPosition {
+    pub auction: Pubkey,
+    pub collateral_amount: u64,
+    pub cost: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LiquidationStrategyConfig {
+    pub deposit_limit: u64,
+    pub performance_fee: u64,
+    pub fee_manager: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub collateral_token_acc: Pubkey,
+    pub oracle: Pubkey,
+}
+
+impl LiquidationStrategy {
+    fn validate_remaining(&self, remaining: &[AccountInfo]) -> Result<()> {
+        if remaining.len() < 6 {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        if remaining[1].key() != self.oracle
+            || remaining[4].key() != self.collateral_token_acc
+            || remaining[5].key() != mock_auction::ID
+        {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        Ok(())
+    }
+
+    fn load_oracle(&self, oracle: &AccountInfo) -> Result<PriceOracle> {
+        if oracle.key() != self.oracle || *oracle.owner != mock_auction::ID {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        let oracle = PriceOracle::try_deserialize(&mut &oracle.try_borrow_data()?[..])?;
+        if oracle.collateral_mint != self.collateral_mint || oracle.underlying_mint != self.underlying_mint {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        Ok(oracle)
+    }
+
+    fn collateral_balance(account: &AccountInfo) -> Result<u64> {
+        let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        Ok(token_account.amount)
+    }
+
+    fn open_collateral(&self) -> u64 {
+        self.positions.iter().map(|p| p.collateral_amount).sum()
+    }
+
+    fn update_positions_value(&mut self, oracle: &AccountInfo) -> Result<()> {
+        let oracle = self.load_oracle(oracle)?;
+        self.positions_value = oracle.collateral_value(self.open_collateral());
+        Ok(())
+    }
+
+    fn trade_accounts<'info>(
+        remaining: &[AccountInfo<'info>],
+        strategy: AccountInfo<'info>,
+        underlying_token_account: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+    ) -> Trade<'info> {
+        Trade {
+            auction: remaining[0].clone(),
+            oracle: remaining[1].clone(),
+            underlying_vault: remaining[2].clone(),
+            collateral_vault: remaining[3].clone(),
+            user_underlying_account: underlying_token_account,
+            user_collateral_account: remaining[4].clone(),
+            user: strategy,
+            token_program,
+        }
+    }
+
+    /// Sells the collateral of a position back at the oracle price, realising the gain or loss
+    pub fn close_position<'info>(
+        &mut self,
+        strategy: AccountInfo<'info>,
+        underlying_token_account: &mut InterfaceAccount<'info, InterfaceTokenAccount>,
+        token_program: AccountInfo<'info>,
+        remaining: &[AccountInfo<'info>],
+        index: usize,
+    ) -> Result<()> {
+        self.validate_remaining(remaining)?;
+
+        let position = *self.positions.get(index).ok_or(ErrorCode::PositionNotFound)?;
+        if position.collateral_amount == 0 {
+            return Err(ErrorCode::PositionNotFound.into());
+        }
+
+        let pre_balance = underlying_token_account.amount;
+
+        mock_auction::cpi::sell(
+            CpiContext::new_with_signer(
+                remaining[5].clone(),
+                Self::trade_accounts(remaining, strategy, underlying_token_account.to_account_info(), token_program),
+                &[&self.seeds()],
+            ),
+            position.collateral_amount,
+        )?;
+
+        underlying_token_account.reload()?;
+        let proceeds = underlying_token_account.amount - pre_balance;
+
+        self.positions[index] = LiquidationPosition::default();
+        self.total_invested -= position.cost;
+        self.idle_balance = underlying_token_account.amount;
+        self.update_positions_value(&remaining[1])?;
+
+        emit!(
+            LiquidationPositionClosedEvent {
+                account_key: self.key(),
+                auction: position.auction,
+                collateral_amount: position.collateral_amount,
+                cost: position.cost,
+                proceeds,
+            }
+        );
+
+        Ok(())
+    }
+}
+
+impl StrategyManagement for LiquidationStrategy {
+    fn manager(&self) -> Pubkey {
+        self.manager
+    }
+
+    fn set_manager(&mut self, manager: Pubkey) -> Result<()> {
+        self.manager = manager;
+        Ok(())
+    }
+
+    fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn shutdown(&mut self) {
+        self.is_shutdown = true;
+    }
+
+    fn uses_roles(&self) -> bool {
+        self.uses_roles
+    }
+
+    fn set_uses_roles(&mut self, enabled: bool) {
+        self.uses_roles = enabled;
+    }
+}
+
+impl Strategy for LiquidationStrategy {
+    fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_assets += amount;
+        self.idle_balance += amount;
+
+        emit!(
+            StrategyDepositEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.total_assets -= amount;
+        self.idle_balance = self.idle_balance.saturating_sub(amount);
+
+        emit!(
+            StrategyWithdrawEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw_fees(&mut self, amount: u64) -> Result<()> {
+        self.fee_data.fee_balance -= amount;
+        Ok(())
+    }
+
+    /// gains are realised by closing positions and picked up by report
+    fn report_profit<'info>(&mut self, _accounts: &ReportProfit<'info>, _remaining: &[AccountInfo<'info>], _profit: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn report_loss<'info>(&mut self, _accounts: &ReportLoss<'info>, _remaining: &[AccountInfo<'info>], _loss: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    /// remaining[0] - oracle
+    fn harvest_and_report<'info>(&mut self, accounts: &Report<'info>, remaining: &[AccountInfo<'info>]) -> Result<u64> {
+        if accounts.underlying_token_account.key() != self.underlying_token_acc {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        self.update_positions_value(remaining.first().ok_or(ErrorCode::InvalidAccount)?)?;
+        self.idle_balance = accounts.underlying_token_account.amount;
+
+        let new_total_assets = accounts.underlying_token_account.amount + self.positions_value;
+        Ok(new_total_assets)
+    }
+
+    /// opens a position by bidding `amount` of underlying in the auction
+    fn deploy_funds<'info>(&mut self, accounts: &DeployFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        self.validate_remaining(remaining)?;
+
+        if accounts.underlying_token_account.amount < amount {
+            return Err(ErrorCode::InsufficientFunds.into());
+        }
+
+        let index = self.positions.iter()
+            .position(|p| p.collateral_amount == 0)
+            .ok_or(ErrorCode::NoFreePositionSlot)?;
+
+        let pre_balance = Self::collateral_balance(&remaining[4])?;
+
+        mock_auction::cpi::bid(
+            CpiContext::new_with_signer(
+                remaining[5].clone(),
+                Self::trade_accounts(
+                    remaining,
+                    accounts.strategy.to_account_info(),
+                    accounts.underlying_token_account.to_account_info(),
+                    accounts.token_program.to_account_info(),
+                ),
+                &[&self.seeds()],
+            ),
+            amount,
+        )?;
+
+        let collateral_amount = Self::collateral_balance(&remaining[4])? - pre_balance;
+
+        self.positions[index] = LiquidationPosition {
+            auction: remaining[0].key(),
+            collateral_amount,
+            cost: amount,
+        };
+        self.total_invested += amount;
+        self.idle_balance = accounts.underlying_token_account.amount - amount;
+        self.update_positions_value(&remaining[1])?;
+
+        emit!(
+            LiquidationPositionOpenedEvent {
+                account_key: self.key(),
+                auction: remaining[0].key(),
+                index: index as u8,
+                collateral_amount,
+                cost: amount,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn can_free_funds(&self) -> bool {
+        true
+    }
+
+    /// closes the positions bought in the auction of the remaining accounts until `amount` is covered
+    fn free_funds<'info>(&mut self, accounts: &FreeFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        self.validate_remaining(remaining)?;
+
+        let mut underlying_token_account = accounts.underlying_token_account.clone();
+        let pre_balance = underlying_token_account.amount;
+
+        for index in 0..MAX_LIQUIDATION_POSITIONS {
+            if underlying_token_account.amount - pre_balance >= amount {
+                break;
+            }
+
+            let position = self.positions[index];
+            if position.collateral_amount == 0 || position.auction != remaining[0].key() {
+                continue;
+            }
+
+            self.close_position(
+                accounts.strategy.to_account_info(),
+                &mut underlying_token_account,
+                accounts.token_program.to_account_info(),
+                remaining,
+                index,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn set_total_assets(&mut self, total_assets: u64) {
+        self.total_assets = total_assets;
+    }
+}
+
+impl StretegyGetters for LiquidationStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Liquidation
+    }
+
+    fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    fn total_assets(&self) -> u64 {
+        self.total_assets
+    }
+
+    fn available_deposit(&self) -> u64 {
+        if self.is_shutdown {
+            return 0;
+        }
+        self.deposit_limit.saturating_sub(self.total_assets)
+    }
+
+    /// only the idle underlying can be withdrawn, positions are valued at the oracle price
+    fn available_withdraw(&self) -> u64 {
+        std::cmp::min(self.total_assets.saturating_sub(self.positions_value), self.idle_balance)
+    }
+
+    fn token_account(&self) -> Pubkey {
+        self.underlying_token_acc
+    }
+
+    fn underlying_mint(&self) -> Pubkey {
+        self.underlying_mint
+    }
+
+    fn fee_data(&mut self) -> &mut FeeData {
+        &mut self.fee_data
+    }
+}
+
+impl StrategyInit for LiquidationStrategy {
+    fn init(
+        &mut self,
+        bump: u8,
+        index: u64,
+        vault: Pubkey,
+        underlying_mint: &InterfaceAccount<Mint>,
+        underlying_token_acc: Pubkey,
+        config_bytes: Vec<u8>
+    ) -> Result<()> {
+        let config = LiquidationStrategyConfig::try_from_slice(&config_bytes)
+        .map_err(|_| ErrorCode::InvalidStrategyConfig)?;
+
+        self.bump = [bump];
+        self.index_bytes = index.to_le_bytes();
+        self.vault = vault;
+        self.underlying_mint = underlying_mint.key();
+        self.undelying_decimals = underlying_mint.decimals;
+        self.underlying_token_acc = underlying_token_acc;
+        self.deposit_limit = config.deposit_limit;
+        self.collateral_mint = config.collateral_mint;
+        self.collateral_token_acc = config.collateral_token_acc;
+        self.oracle = config.oracle;
+        self.total_assets = 0;
+        self.total_invested = 0;
+        self.positions_value = 0;
+        self.idle_balance = 0;
+
+        self.fee_data = FeeData {
+            fee_manager: config.fee_manager,
+            performance_fee: config.performance_fee,
+            fee_balance: 0,
+        };
+        self.version = STRATEGY_VERSION;
+
+        emit!(
+            StrategyInitEvent
+            {
+                account_key: self.key(),
+                strategy_type: String::from("liquidation"),
+                vault: self.vault,
+                underlying_mint: self.underlying_mint,
+                underlying_token_acc: self.underlying_token_acc,
+                undelying_decimals: self.undelying_decimals,
+                deposit_limit: self.deposit_limit,
+                deposit_period_ends: 0,
+                lock_period_ends: 0,
+            });
+
+        Ok(())
+    }
+}
+
+impl StrategyDataAccount for LiquidationStrategy {
+    fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        self.try_to_vec().map_err(|_| ErrorCode::SerializationError.into()).and_then(|vec| {
+            writer.write_all(&vec).map_err(|_| ErrorCode::SerializationError.into())
+        })
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn seeds(&self) -> [&[u8]; 3] {
+        [
+            self.vault.as_ref(),
+            self.index_bytes.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
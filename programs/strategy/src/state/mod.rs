@@ -1,13 +1,33 @@
+pub mod allocator_strategy;
 pub mod base_strategy;
 pub mod config;
 pub mod fee_data;
+pub mod keeper_trigger;
+pub mod legacy;
+pub mod investor_strategy;
+#[cfg(feature = "lending")]
+pub mod lending_strategy;
+#[cfg(feature = "liquidation")]
+pub mod liquidation_strategy;
+pub mod pending_transfer;
+pub mod rwa_strategy;
 pub mod trade_fintech_srategy;
 pub mod simple_strategy;
 pub mod strategy_type;
 
+pub use allocator_strategy::*;
 pub use base_strategy::*;
 pub use config::*;
 pub use fee_data::*;
+pub use keeper_trigger::*;
+pub use legacy::*;
+pub use investor_strategy::*;
+#[cfg(feature = "lending")]
+pub use lending_strategy::*;
+#[cfg(feature = "liquidation")]
+pub use liquidation_strategy::*;
+pub use pending_transfer::*;
+pub use rwa_strategy::*;
 pub use trade_fintech_srategy::*;
 pub use simple_strategy::*;
 pub use strategy_type::*;
\ No newline at end of file
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DISCRIMINATOR_LEN;
+
+/// Manager or fee manager proposed for a strategy, waiting to be accepted by the proposed key
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct PendingTransfer {
+    pub strategy: Pubkey,
+    pub proposed: Pubkey,
+    /// gets the rent back once the proposal is accepted or cancelled
+    pub payer: Pubkey,
+}
+
+impl PendingTransfer {
+    pub const LEN: usize = DISCRIMINATOR_LEN + PendingTransfer::INIT_SPACE;
+}
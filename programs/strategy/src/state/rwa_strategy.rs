@@ -0,0 +1,412 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use super::base_strategy::*;
+use super::StrategyType;
+use super::keeper_trigger::*;
+use super::fee_data::*;
+
+use crate::constants::STRATEGY_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{NavUpdatedEvent, RWAConfigUpdatedEvent, StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent};
+use crate::utils::token;
+use crate::instructions::{Report, ReportProfit, ReportLoss, DeployFunds, FreeFunds};
+
+/// Strategy investing into off-chain real world assets.
+/// Deployed funds are sent to a custodian, their value (NAV) is reported
+/// by the attestor through ed25519 signed messages.
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct RWAStrategy {
+    /// Bump to identify PDA
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+
+    /// vault
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub undelying_decimals: u8,
+
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+
+    /// token account of the custodian receiving deployed funds
+    pub custodian: Pubkey,
+    pub attestor: Pubkey,
+
+    /// value of the off-chain assets from the last attestation
+    pub nav: u64,
+    pub nav_timestamp: i64,
+    pub nav_nonce: u64,
+    pub max_nav_staleness: i64,
+
+    /// withdrawals are only possible during windows of `redemption_window_duration` seconds
+    /// opening every `redemption_period` seconds from `redemption_window_start`
+    pub redemption_window_start: i64,
+    pub redemption_period: i64,
+    pub redemption_window_duration: i64,
+
+    pub fee_data: FeeData,
+
+    pub version: u8,
+    /// set by shutdown_strategy, no new deposits are accepted
+    pub is_shutdown: bool,
+    /// authorization through strategy scoped access_control roles besides the manager
+    pub uses_roles: bool,
+    pub reserved_flags: [u8; 6],
+    pub reserved: [u64; 7],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RWAStrategyConfig {
+    pub deposit_limit: u64,
+    pub performance_fee: u64,
+    pub fee_manager: Pubkey,
+    pub custodian: Pubkey,
+    pub params: RWAParams,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RWAParams {
+    pub attestor: Pubkey,
+    pub max_nav_staleness: i64,
+    pub redemption_window_start: i64,
+    pub redemption_period: i64,
+    pub redemption_window_duration: i64,
+}
+
+/// Message signed by the attestor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NavAttestation {
+    pub strategy: Pubkey,
+    pub nav: u64,
+    pub timestamp: i64,
+    pub nonce: u64,
+}
+
+impl RWAStrategy {
+    pub fn set_params(&mut self, params: RWAParams) -> Result<()> {
+        if params.max_nav_staleness <= 0
+            || params.redemption_period <= 0
+            || params.redemption_window_duration <= 0
+            || params.redemption_window_duration > params.redemption_period
+        {
+            return Err(ErrorCode::InvalidStrategyConfig.into());
+        }
+
+        self.attestor = params.attestor;
+        self.max_nav_staleness = params.max_nav_staleness;
+        self.redemption_window_start = params.redemption_window_start;
+        self.redemption_period = params.redemption_period;
+        self.redemption_window_duration = params.redemption_window_duration;
+
+        emit!(
+            RWAConfigUpdatedEvent {
+                account_key: self.key(),
+                attestor: self.attestor,
+                max_nav_staleness: self.max_nav_staleness,
+                redemption_window_start: self.redemption_window_start,
+                redemption_period: self.redemption_period,
+                redemption_window_duration: self.redemption_window_duration,
+            }
+        );
+
+        Ok(())
+    }
+
+    pub fn is_nav_fresh(&self, timestamp: i64) -> bool {
+        timestamp - self.nav_timestamp <= self.max_nav_staleness
+    }
+
+    pub fn is_redemption_window_open(&self, timestamp: i64) -> bool {
+        if timestamp < self.redemption_window_start {
+            return false;
+        }
+        (timestamp - self.redemption_window_start) % self.redemption_period < self.redemption_window_duration
+    }
+
+    /// Applies an attestation, the signature itself is verified by the caller
+    pub fn update_nav(&mut self, attestation: &NavAttestation, now: i64) -> Result<()> {
+        if attestation.strategy != self.key() {
+            return Err(ErrorCode::InvalidAttestation.into());
+        }
+
+        if attestation.nonce <= self.nav_nonce {
+            return Err(ErrorCode::InvalidNonce.into());
+        }
+
+        if attestation.timestamp > now
+            || attestation.timestamp < self.nav_timestamp
+            || now - attestation.timestamp > self.max_nav_staleness
+        {
+            return Err(ErrorCode::StaleNav.into());
+        }
+
+        self.nav = attestation.nav;
+        self.nav_timestamp = attestation.timestamp;
+        self.nav_nonce = attestation.nonce;
+
+        Ok(())
+    }
+
+    pub fn emit_nav_updated(&self) {
+        emit!(
+            NavUpdatedEvent {
+                account_key: self.key(),
+                nav: self.nav,
+                timestamp: self.nav_timestamp,
+                nonce: self.nav_nonce,
+                total_assets: self.total_assets,
+            }
+        );
+    }
+}
+
+impl StrategyManagement for RWAStrategy {
+    fn manager(&self) -> Pubkey {
+        self.manager
+    }
+
+    fn set_manager(&mut self, manager: Pubkey) -> Result<()> {
+        self.manager = manager;
+        Ok(())
+    }
+
+    fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn shutdown(&mut self) {
+        self.is_shutdown = true;
+    }
+
+    fn uses_roles(&self) -> bool {
+        self.uses_roles
+    }
+
+    fn set_uses_roles(&mut self, enabled: bool) {
+        self.uses_roles = enabled;
+    }
+}
+
+impl Strategy for RWAStrategy {
+    fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_assets += amount;
+
+        emit!(
+            StrategyDepositEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.total_assets -= amount;
+
+        emit!(
+            StrategyWithdrawEvent
+            {
+                account_key: self.key(),
+                amount,
+                total_assets: self.total_assets,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn withdraw_fees(&mut self, amount: u64) -> Result<()> {
+        self.fee_data.fee_balance -= amount;
+        Ok(())
+    }
+
+    /// gains and losses come only from NAV attestations
+    fn report_profit<'info>(&mut self, _accounts: &ReportProfit<'info>, _remaining: &[AccountInfo<'info>], _profit: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn report_loss<'info>(&mut self, _accounts: &ReportLoss<'info>, _remaining: &[AccountInfo<'info>], _loss: u64) -> Result<()> {
+        Err(ErrorCode::NotSupported.into())
+    }
+
+    fn harvest_and_report<'info>(&mut self, accounts: &Report<'info>, _remaining: &[AccountInfo<'info>]) -> Result<u64> {
+        if accounts.underlying_token_account.key() != self.underlying_token_acc {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        if !self.is_nav_fresh(Clock::get()?.unix_timestamp) {
+            return Err(ErrorCode::StaleNav.into());
+        }
+
+        let new_total_assets = accounts.underlying_token_account.amount + self.nav;
+        Ok(new_total_assets)
+    }
+
+    /// accounts should be the next:
+    /// [0] - custodian token account
+    fn deploy_funds<'info>(&mut self, accounts: &DeployFunds<'info>, remaining: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+        if remaining.is_empty() || remaining[0].key() != self.custodian {
+            return Err(ErrorCode::InvalidAccount.into());
+        }
+
+        token::transfer_with_signer(
+            accounts.token_program.to_account_info(),
+            accounts.underlying_token_account.to_account_info(),
+            remaining[0].to_account_info(),
+            accounts.strategy.to_account_info(),
+            amount,
+            &self.seeds()
+        )?;
+
+        // deployed funds are valued at cost until the next attestation
+        self.nav += amount;
+        Ok(())
+    }
+
+    /// the custodian returns funds to the underlying token account off-chain
+    fn free_funds<'info>(&mut self, _accounts: &FreeFunds<'info>, _remaining: &[AccountInfo<'info>], _amount: u64) -> Result<()> {
+        Ok(())
+    }
+
+    fn report_trigger(&self, idle: u64, timestamp: i64) -> KeeperTrigger {
+        let value = idle.saturating_sub(self.fee_data.fee_balance) + self.nav;
+        if self.is_nav_fresh(timestamp) && value != self.total_assets {
+            return KeeperTrigger::act(TriggerReason::NavChanged);
+        }
+        KeeperTrigger::none()
+    }
+
+    fn set_total_assets(&mut self, total_assets: u64) {
+        self.total_assets = total_assets;
+    }
+}
+
+impl StretegyGetters for RWAStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::RWA
+    }
+
+    fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    fn total_assets(&self) -> u64 {
+        self.total_assets
+    }
+
+    fn available_deposit(&self) -> u64 {
+        if self.is_shutdown {
+            return 0;
+        }
+        self.deposit_limit.saturating_sub(self.total_assets)
+    }
+
+    /// only idle funds can be withdrawn and only during a redemption window with a fresh NAV
+    fn available_withdraw(&self) -> u64 {
+        match Clock::get() {
+            Ok(clock) => {
+                if self.is_redemption_window_open(clock.unix_timestamp) && self.is_nav_fresh(clock.unix_timestamp) {
+                    self.total_assets.saturating_sub(self.nav)
+                } else {
+                    0
+                }
+            }
+            Err(_) => 0,
+        }
+    }
+
+    fn token_account(&self) -> Pubkey {
+        self.underlying_token_acc
+    }
+
+    fn underlying_mint(&self) -> Pubkey {
+        self.underlying_mint
+    }
+
+    fn fee_data(&mut self) -> &mut FeeData {
+        &mut self.fee_data
+    }
+}
+
+impl StrategyInit for RWAStrategy {
+    fn init(
+        &mut self,
+        bump: u8,
+        index: u64,
+        vault: Pubkey,
+        underlying_mint: &InterfaceAccount<Mint>,
+        underlying_token_acc: Pubkey,
+        config_bytes: Vec<u8>
+    ) -> Result<()> {
+        let config = RWAStrategyConfig::try_from_slice(&config_bytes)
+        .map_err(|_| ErrorCode::InvalidStrategyConfig)?;
+
+        self.bump = [bump];
+        self.index_bytes = index.to_le_bytes();
+        self.vault = vault;
+        self.underlying_mint = underlying_mint.key();
+        self.undelying_decimals = underlying_mint.decimals;
+        self.underlying_token_acc = underlying_token_acc;
+        self.deposit_limit = config.deposit_limit;
+        self.custodian = config.custodian;
+        self.total_assets = 0;
+        self.nav = 0;
+        self.nav_timestamp = Clock::get()?.unix_timestamp;
+        self.nav_nonce = 0;
+        self.set_params(config.params)?;
+
+        self.fee_data = FeeData {
+            fee_manager: config.fee_manager,
+            performance_fee: config.performance_fee,
+            fee_balance: 0,
+        };
+        self.version = STRATEGY_VERSION;
+
+        emit!(
+            StrategyInitEvent
+            {
+                account_key: self.key(),
+                strategy_type: String::from("rwa"),
+                vault: self.vault,
+                underlying_mint: self.underlying_mint,
+                underlying_token_acc: self.underlying_token_acc,
+                undelying_decimals: self.undelying_decimals,
+                deposit_limit: self.deposit_limit,
+                deposit_period_ends: 0,
+                lock_period_ends: 0,
+            });
+
+        Ok(())
+    }
+}
+
+impl StrategyDataAccount for RWAStrategy {
+    fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()> {
+        self.try_to_vec().map_err(|_| ErrorCode::SerializationError.into()).and_then(|vec| {
+            writer.write_all(&vec).map_err(|_| ErrorCode::SerializationError.into())
+        })
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn seeds(&self) -> [&[u8]; 3] {
+        [
+            self.vault.as_ref(),
+            self.index_bytes.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
@@ -5,6 +5,7 @@ use super::base_strategy::*;
 use super::StrategyType;
 use super::fee_data::*;
 
+use crate::constants::STRATEGY_VERSION;
 use crate::error::ErrorCode;
 use crate::events::{StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent};
 use crate::utils::token;
@@ -29,6 +30,14 @@ pub struct SimpleStrategy {
     pub deposit_limit: u64,
 
     pub fee_data: FeeData,
+
+    pub version: u8,
+    /// set by shutdown_strategy, no new deposits are accepted
+    pub is_shutdown: bool,
+    /// authorization through strategy scoped access_control roles besides the manager
+    pub uses_roles: bool,
+    pub reserved_flags: [u8; 6],
+    pub reserved: [u64; 7],
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug)]
@@ -47,6 +56,22 @@ impl StrategyManagement for SimpleStrategy {
         self.manager = manager;
         Ok(())
     }
+
+    fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn shutdown(&mut self) {
+        self.is_shutdown = true;
+    }
+
+    fn uses_roles(&self) -> bool {
+        self.uses_roles
+    }
+
+    fn set_uses_roles(&mut self, enabled: bool) {
+        self.uses_roles = enabled;
+    }
 }
 
 impl StretegyGetters for SimpleStrategy {
@@ -63,6 +88,9 @@ impl StretegyGetters for SimpleStrategy {
     }
 
     fn available_deposit(&self) -> u64 {
+        if self.is_shutdown {
+            return 0;
+        }
         self.deposit_limit - self.total_assets
     }
 
@@ -228,6 +256,7 @@ impl StrategyInit for SimpleStrategy {
             performance_fee: config.performance_fee,
             fee_balance: 0,
         };
+        self.version = STRATEGY_VERSION;
 
         emit!(
             StrategyInitEvent 
@@ -252,6 +281,14 @@ impl StrategyDataAccount for SimpleStrategy {
             writer.write_all(&vec).map_err(|_| ErrorCode::SerializationError.into())
         })
     }
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
     fn seeds(&self) -> [&[u8]; 3] {
         [
             self.vault.as_ref(),
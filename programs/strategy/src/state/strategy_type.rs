@@ -4,9 +4,18 @@ use crate::constants::DISCRIMINATOR_LEN;
 use crate::state::{
     simple_strategy::SimpleStrategy,
     trade_fintech_srategy::TradeFintechStrategy,
+    investor_strategy::InvestorStrategy,
+    allocator_strategy::AllocatorStrategy,
+    rwa_strategy::RWAStrategy,
 };
+#[cfg(feature = "lending")]
+use crate::state::lending_strategy::LendingStrategy;
+#[cfg(feature = "liquidation")]
+use crate::state::liquidation_strategy::LiquidationStrategy;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+/// Lending and Liquidation are only available with the matching features,
+/// the variants stay so the other types keep their encoding
 pub enum StrategyType {
     Simple,
     TradeFintech,
@@ -14,6 +23,7 @@ pub enum StrategyType {
     Lending,
     Liquidation,
     Investor,
+    Allocator,
 }
 
 impl StrategyType {
@@ -21,10 +31,18 @@ impl StrategyType {
         match self {
             StrategyType::Simple => DISCRIMINATOR_LEN + SimpleStrategy::INIT_SPACE,
             StrategyType::TradeFintech => DISCRIMINATOR_LEN + TradeFintechStrategy::INIT_SPACE,
-            StrategyType::RWA => 0,
-            StrategyType::Lending => 0,
-            StrategyType::Liquidation => 0,
-            StrategyType::Investor => 0,
+            StrategyType::RWA => DISCRIMINATOR_LEN + RWAStrategy::INIT_SPACE,
+            #[cfg(feature = "lending")]
+            StrategyType::Lending => DISCRIMINATOR_LEN + LendingStrategy::INIT_SPACE,
+            #[cfg(feature = "liquidation")]
+            StrategyType::Liquidation => DISCRIMINATOR_LEN + LiquidationStrategy::INIT_SPACE,
+            // rejected by init_strategy
+            #[cfg(not(feature = "lending"))]
+            StrategyType::Lending => DISCRIMINATOR_LEN,
+            #[cfg(not(feature = "liquidation"))]
+            StrategyType::Liquidation => DISCRIMINATOR_LEN,
+            StrategyType::Investor => DISCRIMINATOR_LEN + InvestorStrategy::INIT_SPACE,
+            StrategyType::Allocator => DISCRIMINATOR_LEN + AllocatorStrategy::INIT_SPACE,
         }
     }
 }
\ No newline at end of file
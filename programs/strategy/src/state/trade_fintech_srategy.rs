@@ -3,7 +3,9 @@ use anchor_spl::token_interface::Mint;
 
 use super::base_strategy::*;
 use super::StrategyType;
+use super::keeper_trigger::*;
 use super::fee_data::*;
+use crate::constants::STRATEGY_VERSION;
 use crate::error::ErrorCode;
 use crate::events::{StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent};
 use crate::utils::token;
@@ -31,6 +33,14 @@ pub struct TradeFintechStrategy {
     pub lock_period_ends: i64,
 
     pub fee_data: FeeData,
+
+    pub version: u8,
+    /// set by shutdown_strategy, no new deposits are accepted
+    pub is_shutdown: bool,
+    /// authorization through strategy scoped access_control roles besides the manager
+    pub uses_roles: bool,
+    pub reserved_flags: [u8; 6],
+    pub reserved: [u64; 7],
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -59,6 +69,22 @@ impl StrategyManagement for TradeFintechStrategy {
         self.manager = manager;
         Ok(())
     }
+
+    fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn shutdown(&mut self) {
+        self.is_shutdown = true;
+    }
+
+    fn uses_roles(&self) -> bool {
+        self.uses_roles
+    }
+
+    fn set_uses_roles(&mut self, enabled: bool) {
+        self.uses_roles = enabled;
+    }
 }
 
 impl Strategy for TradeFintechStrategy {
@@ -204,6 +230,13 @@ impl Strategy for TradeFintechStrategy {
         Ok(())
     }
 
+    fn report_trigger(&self, _idle: u64, timestamp: i64) -> KeeperTrigger {
+        if self.total_invested > 0 && timestamp > self.lock_period_ends {
+            return KeeperTrigger::act(TriggerReason::LockPeriodEnded);
+        }
+        KeeperTrigger::none()
+    }
+
     fn set_total_assets(&mut self, total_assets: u64) {
         self.total_assets = total_assets;
     }
@@ -223,6 +256,9 @@ impl StretegyGetters for TradeFintechStrategy {
     }
 
     fn available_deposit(&self) -> u64 {
+        if self.is_shutdown {
+            return 0;
+        }
         // if deposit_period_ends is in the past, return 0
         match Clock::get() {
             Ok(clock) => {
@@ -293,6 +329,7 @@ impl StrategyInit for TradeFintechStrategy {
             performance_fee: config.performance_fee,
             fee_balance: 0,
         };
+        self.version = STRATEGY_VERSION;
 
         emit!(
             StrategyInitEvent 
@@ -319,6 +356,14 @@ impl StrategyDataAccount for TradeFintechStrategy {
         })
     }
     
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
     fn seeds(&self) -> [&[u8]; 3] {
         [
             self.vault.as_ref(),
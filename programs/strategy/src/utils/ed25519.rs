@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    instruction::Instruction,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::error::ErrorCode;
+
+// layout of the ed25519 program instruction data
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+
+/// Checks that the instruction preceding the current one is an ed25519 program instruction
+/// verifying a single signature of `signer` over `message`.
+/// The ed25519 program fails the whole transaction if the signature is invalid,
+/// so we only need to make sure it checked the expected key and message.
+pub fn verify_previous_ed25519_ix(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)?;
+    if current_index == 0 {
+        return Err(ErrorCode::InvalidAttestation.into());
+    }
+
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions)?;
+    verify_ed25519_ix(&ix, signer, message)
+}
+
+fn verify_ed25519_ix(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    if ix.program_id != ed25519_program::ID || !ix.accounts.is_empty() {
+        return Err(ErrorCode::InvalidAttestation.into());
+    }
+
+    let data = &ix.data;
+    if data.len() < SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN || data[0] != 1 {
+        return Err(ErrorCode::InvalidAttestation.into());
+    }
+
+    let offsets = &data[SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN];
+    let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+
+    let signature_ix_index = read_u16(2);
+    let pubkey_offset = read_u16(4) as usize;
+    let pubkey_ix_index = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    let message_ix_index = read_u16(12);
+
+    // all the data must be taken from the ed25519 instruction itself
+    if signature_ix_index != u16::MAX || pubkey_ix_index != u16::MAX || message_ix_index != u16::MAX {
+        return Err(ErrorCode::InvalidAttestation.into());
+    }
+
+    let pubkey = data.get(pubkey_offset..pubkey_offset + PUBKEY_LEN)
+        .ok_or(ErrorCode::InvalidAttestation)?;
+    let signed_message = data.get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidAttestation)?;
+
+    if pubkey != signer.as_ref() || signed_message != message {
+        return Err(ErrorCode::InvalidAttestation.into());
+    }
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
+pub mod ed25519;
+pub mod roles;
 pub mod unchecked_strategy;
 pub mod token;
+pub mod vault_cpi;
 
 pub use unchecked_strategy::*;
 pub use token::*;
\ No newline at end of file
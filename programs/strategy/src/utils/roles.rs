@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::STRATEGY_ROLE_SEED,
+    state::{Role, UserRole},
+};
+
+use crate::error::ErrorCode;
+use crate::state::StrategyManagement;
+
+/// The manager can always act on the strategy. With role based management enabled
+/// the signer can also hold the strategy scoped role, its account is looked up
+/// among the remaining accounts.
+pub fn check_role<S>(
+    strategy: &S,
+    strategy_key: &Pubkey,
+    signer: &Pubkey,
+    role: Role,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()>
+where
+    S: StrategyManagement + ?Sized,
+{
+    if *signer == strategy.manager() {
+        return Ok(());
+    }
+
+    if !strategy.uses_roles() {
+        return Err(ErrorCode::AccessDenied.into());
+    }
+
+    let expected_roles_key = Pubkey::find_program_address(
+        &[
+            STRATEGY_ROLE_SEED.as_bytes(),
+            strategy_key.as_ref(),
+            signer.as_ref(),
+            role.to_seed().as_ref(),
+        ],
+        &access_control::ID,
+    ).0;
+
+    let roles_acc = remaining_accounts
+        .iter()
+        .find(|acc| acc.key() == expected_roles_key && *acc.owner == access_control::ID)
+        .ok_or(ErrorCode::AccessDenied)?;
+
+    let roles = UserRole::try_deserialize(&mut &roles_acc.try_borrow_data()?[..])?;
+    if !roles.has_role {
+        return Err(ErrorCode::AccessDenied.into());
+    }
+
+    Ok(())
+}
@@ -51,12 +51,37 @@ pub fn deserialize(strategy: &AccountInfo) -> Result<Box<dyn Strategy>> {
 
     match discriminator {
         SimpleStrategy::DISCRIMINATOR => {
-            let strategy = SimpleStrategy::try_from_slice(&strategy_data[8..])
-                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            let strategy = deserialize_versioned::<SimpleStrategy, SimpleStrategyV0>(&strategy_data[8..])?;
             Ok(Box::new(strategy))
         }
         TradeFintechStrategy::DISCRIMINATOR => {
-            let strategy = TradeFintechStrategy::try_from_slice(&strategy_data[8..])
+            let strategy = deserialize_versioned::<TradeFintechStrategy, TradeFintechStrategyV0>(&strategy_data[8..])?;
+            Ok(Box::new(strategy))
+        }
+        #[cfg(feature = "lending")]
+        LendingStrategy::DISCRIMINATOR => {
+            let strategy = LendingStrategy::try_from_slice(&strategy_data[8..])
+                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            Ok(Box::new(strategy))
+        }
+        RWAStrategy::DISCRIMINATOR => {
+            let strategy = RWAStrategy::try_from_slice(&strategy_data[8..])
+                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            Ok(Box::new(strategy))
+        }
+        #[cfg(feature = "liquidation")]
+        LiquidationStrategy::DISCRIMINATOR => {
+            let strategy = LiquidationStrategy::try_from_slice(&strategy_data[8..])
+                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            Ok(Box::new(strategy))
+        }
+        InvestorStrategy::DISCRIMINATOR => {
+            let strategy = InvestorStrategy::try_from_slice(&strategy_data[8..])
+                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            Ok(Box::new(strategy))
+        }
+        AllocatorStrategy::DISCRIMINATOR => {
+            let strategy = AllocatorStrategy::try_from_slice(&strategy_data[8..])
                 .map_err(|_| ErrorCode::InvalidStrategyData)?;
             Ok(Box::new(strategy))
         }
@@ -65,4 +90,33 @@ pub fn deserialize(strategy: &AccountInfo) -> Result<Box<dyn Strategy>> {
             Err(ErrorCode::InvalidStrategyData.into())
         }
     }
-}
\ No newline at end of file
+}
+
+/// Legacy accounts have no version field, so they are recognised by their size
+/// and converted to the current layout with version 0.
+fn deserialize_versioned<T, L>(data: &[u8]) -> Result<T>
+where
+    T: AnchorDeserialize + From<L>,
+    L: AnchorDeserialize + Space,
+{
+    if data.len() == L::INIT_SPACE {
+        let legacy = L::try_from_slice(data).map_err(|_| ErrorCode::InvalidStrategyData)?;
+        return Ok(legacy.into());
+    }
+
+    let strategy = T::try_from_slice(data).map_err(|_| ErrorCode::InvalidStrategyData)?;
+    Ok(strategy)
+}
+
+/// Deserializes a strategy of a known type, used by the instructions specific to one strategy type
+pub fn deserialize_as<T>(strategy: &AccountInfo) -> Result<T>
+where
+    T: AnchorDeserialize + Discriminator,
+{
+    let strategy_data = strategy.try_borrow_data()?;
+    if strategy_data.len() < 8 || strategy_data[0..8] != T::DISCRIMINATOR {
+        return Err(ErrorCode::InvalidAccountType.into());
+    }
+
+    T::try_from_slice(&strategy_data[8..]).map_err(|_| ErrorCode::InvalidStrategyData.into())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke, invoke_signed},
+};
+
+use crate::constants::TOKENIZED_VAULT_PROGRAM_ID;
+use crate::error::ErrorCode;
+
+// tokenized_vault depends on this crate, so its instructions are built by hand
+// instead of going through the generated cpi module.
+
+fn sighash(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+pub struct VaultAccounts<'a, 'info> {
+    pub vault: &'a AccountInfo<'info>,
+    pub vault_token_account: &'a AccountInfo<'info>,
+    pub shares_mint: &'a AccountInfo<'info>,
+    pub user_shares_account: &'a AccountInfo<'info>,
+    pub user_token_account: AccountInfo<'info>,
+    pub user: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub vault_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> VaultAccounts<'a, 'info> {
+    // leading accounts shared by the tokenized_vault Deposit and Withdraw structs
+    fn metas(&self, extra: &[AccountInfo<'info>]) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(self.vault.key(), false),
+            AccountMeta::new(self.user_token_account.key(), false),
+            AccountMeta::new(self.vault_token_account.key(), false),
+            AccountMeta::new(self.shares_mint.key(), false),
+            AccountMeta::new(self.user_shares_account.key(), false),
+            AccountMeta::new(self.user.key(), true),
+            AccountMeta::new_readonly(self.token_program.key(), false),
+        ];
+        metas.extend(extra.iter().map(|acc| if acc.is_writable {
+            AccountMeta::new(acc.key(), acc.is_signer)
+        } else {
+            AccountMeta::new_readonly(acc.key(), acc.is_signer)
+        }));
+        metas
+    }
+
+    fn infos(&self, extra: &[AccountInfo<'info>]) -> Vec<AccountInfo<'info>> {
+        let mut infos = vec![
+            self.vault.clone(),
+            self.user_token_account.clone(),
+            self.vault_token_account.clone(),
+            self.shares_mint.clone(),
+            self.user_shares_account.clone(),
+            self.user.clone(),
+            self.token_program.clone(),
+        ];
+        infos.extend_from_slice(extra);
+        infos
+    }
+}
+
+/// tokenized_vault::deposit, `extra` is forwarded as remaining accounts (e.g. whitelist roles)
+pub fn deposit<'info>(
+    accounts: &VaultAccounts<'_, 'info>,
+    access_control: &AccountInfo<'info>,
+    extra: &[AccountInfo<'info>],
+    amount: u64,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = sighash("deposit").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    let mut extra_accounts = vec![access_control.clone()];
+    extra_accounts.extend_from_slice(extra);
+
+    let ix = Instruction {
+        program_id: TOKENIZED_VAULT_PROGRAM_ID,
+        accounts: accounts.metas(&extra_accounts),
+        data,
+    };
+
+    let mut infos = accounts.infos(&extra_accounts);
+    infos.push(accounts.vault_program.clone());
+    invoke_signed(&ix, &infos, &[seeds]).map_err(Into::into)
+}
+
+/// tokenized_vault::redeem with an empty accounts map, so only the idle funds of the vault are used
+pub fn redeem<'info>(
+    accounts: &VaultAccounts<'_, 'info>,
+    shares: u64,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = sighash("redeem").to_vec();
+    data.extend_from_slice(&shares.to_le_bytes());
+    // max_loss: None
+    data.push(0);
+    // empty accounts map
+    data.extend_from_slice(&0u32.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: TOKENIZED_VAULT_PROGRAM_ID,
+        accounts: accounts.metas(&[]),
+        data,
+    };
+
+    let mut infos = accounts.infos(&[]);
+    infos.push(accounts.vault_program.clone());
+    invoke_signed(&ix, &infos, &[seeds]).map_err(Into::into)
+}
+
+/// Calls a read-only vault view (`preview_redeem`, `max_withdraw`) and reads its return data
+pub fn view<'info>(
+    name: &str,
+    vault: &AccountInfo<'info>,
+    vault_program: &AccountInfo<'info>,
+    shares: u64,
+) -> Result<u64> {
+    let mut data = sighash(name).to_vec();
+    data.extend_from_slice(&shares.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: TOKENIZED_VAULT_PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(vault.key(), false)],
+        data,
+    };
+
+    invoke(&ix, &[vault.clone(), vault_program.clone()])?;
+
+    match get_return_data() {
+        Some((program_id, data)) if program_id == TOKENIZED_VAULT_PROGRAM_ID && data.len() == 8 => {
+            Ok(u64::from_le_bytes(data.try_into().unwrap()))
+        }
+        _ => Err(ErrorCode::InvalidAccount.into()),
+    }
+}
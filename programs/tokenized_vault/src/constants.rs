@@ -5,9 +5,30 @@ pub const UNDERLYING_SEED: &str = "underlying";
 pub const ROLES_SEED: &str = "roles";
 pub const CONFIG_SEED: &str = "config";
 pub const STRATEGY_DATA_SEED: &str = "strategy_data";
+pub const STRATEGY_PROGRAMS_SEED: &str = "strategy_programs";
+pub const INSURANCE_SEED: &str = "insurance";
+pub const TRANCHES_SEED: &str = "tranches";
+pub const TRANCHE_SHARES_SEED: &str = "tranche_shares";
+pub const TRANCHES_POOL_SEED: &str = "tranches_pool";
+pub const EPOCH_SEED: &str = "epoch";
+pub const EPOCH_REQUEST_SEED: &str = "epoch_request";
+pub const EPOCH_SHARES_SEED: &str = "epoch_shares";
+pub const TEMP_WSOL_SEED: &str = "temp_wsol";
+pub const WITHDRAWAL_SESSION_SEED: &str = "withdrawal_session";
+pub const WITHDRAWAL_ESCROW_SEED: &str = "withdrawal_escrow";
 
 pub const MAX_BPS: u64 = 10_000;
 pub const FEE_BPS: u64 = 10_000;
 pub const MAX_BPS_EXTENDED: u64 = 1_000_000_000_000;
 
 pub const DISCRIMINATOR_LEN: usize = 8;
+
+pub const SHARES_DECIMALS: u8 = 9;
+
+pub const MAX_STRATEGY_PROGRAMS: usize = 10;
+
+pub const VAULT_VERSION: u8 = 1;
+pub const STRATEGY_DATA_VERSION: u8 = 2;
+pub const CONFIG_VERSION: u8 = 1;
+
+pub const SECS_PER_YEAR: u64 = 31_556_952;
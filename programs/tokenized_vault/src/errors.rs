@@ -82,4 +82,94 @@ pub enum ErrorCode {
 
     #[msg("Strategy cannot be added")]
     InvalidStrategyToAdd,
+
+    #[msg("Reported profit exceeds health check limit")]
+    ProfitLimitExceeded,
+
+    #[msg("Reported loss exceeds health check limit")]
+    LossLimitExceeded,
+
+    #[msg("Invalid health check limit")]
+    InvalidHealthCheckLimit,
+
+    #[msg("Invalid insurance fee")]
+    InvalidInsuranceFee,
+
+    #[msg("Insurance reserve is below target")]
+    InsuranceBelowTarget,
+
+    #[msg("Vault is tranched")]
+    TranchedVault,
+
+    #[msg("Vault is not tranched")]
+    NotTranchedVault,
+
+    #[msg("Vault is not empty")]
+    VaultNotEmpty,
+
+    #[msg("Tranches account is missing")]
+    TranchesAccountMissing,
+
+    #[msg("Invalid tranches config")]
+    InvalidTranchesConfig,
+
+    #[msg("Junior subordination is below minimum")]
+    SubordinationTooLow,
+
+    #[msg("Vault is epoch based")]
+    EpochBasedVault,
+
+    #[msg("Vault is not epoch based")]
+    NotEpochBasedVault,
+
+    #[msg("Epoch is already settled")]
+    EpochSettled,
+
+    #[msg("Epoch is not settled yet")]
+    EpochNotSettled,
+
+    #[msg("Invalid term config")]
+    InvalidTermConfig,
+
+    #[msg("Deposit window is closed")]
+    DepositWindowClosed,
+
+    #[msg("Vault has not matured yet")]
+    VaultNotMatured,
+
+    #[msg("Vault is paused")]
+    VaultPaused,
+
+    #[msg("Vault is not paused")]
+    VaultNotPaused,
+
+    #[msg("Invalid circuit breaker threshold")]
+    InvalidCircuitBreakerThreshold,
+
+    #[msg("Invalid max loss")]
+    InvalidMaxLoss,
+
+    #[msg("Vault underlying is not the native mint")]
+    NotNativeMintVault,
+
+    #[msg("Vaults cannot be migrated between")]
+    InvalidMigration,
+
+    #[msg("Account is already migrated")]
+    AlreadyMigrated,
+
+    #[msg("Strategy program is not approved for the vault")]
+    StrategyProgramNotApproved,
+
+    #[msg("Invalid strategy program")]
+    InvalidStrategyProgram,
+
+    #[msg("Strategy programs list is full")]
+    StrategyProgramsFull,
+
+    #[msg("Tranche is wiped out")]
+    TrancheWipedOut,
+
+    #[msg("Strategy moved an unexpected amount of the vault assets")]
+    UnexpectedStrategyTransfer,
 }
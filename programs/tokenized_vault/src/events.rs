@@ -95,4 +95,214 @@ pub struct StrategyReportedEvent {
     pub protocol_fees: u64,
     pub total_fees: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct StrategyHealthCheckUpdatedEvent {
+    pub vault_key: Pubkey,
+    pub strategy_key: Pubkey,
+    pub max_profit_bps: u64,
+    pub max_loss_bps: u64,
+}
+
+#[event]
+pub struct StrategyHealthCheckOverriddenEvent {
+    pub vault_key: Pubkey,
+    pub strategy_key: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct InsuranceConfigUpdatedEvent {
+    pub vault_key: Pubkey,
+    pub fee: u64,
+    pub target: u64,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct InsuranceFundedEvent {
+    pub vault_key: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct InsuranceLossCoveredEvent {
+    pub vault_key: Pubkey,
+    pub strategy_key: Pubkey,
+    pub loss: u64,
+    pub shares_burned: u64,
+}
+
+#[event]
+pub struct InsuranceWithdrawnEvent {
+    pub vault_key: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct TranchesInitEvent {
+    pub vault_key: Pubkey,
+    pub senior_mint: Pubkey,
+    pub junior_mint: Pubkey,
+    pub senior_target_yield: u64,
+    pub min_subordination: u64,
+}
+
+#[event]
+pub struct TrancheDepositEvent {
+    pub vault_key: Pubkey,
+    pub tranche: u8,
+    pub amount: u64,
+    pub shares: u64,
+    pub vault_shares: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TrancheWithdrawEvent {
+    pub vault_key: Pubkey,
+    pub tranche: u8,
+    pub assets_to_transfer: u64,
+    pub shares_to_burn: u64,
+    pub vault_shares: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TranchesReportedEvent {
+    pub vault_key: Pubkey,
+    pub senior_assets: u64,
+    pub junior_assets: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpochsInitEvent {
+    pub vault_key: Pubkey,
+    pub epoch_shares_account: Pubkey,
+}
+
+#[event]
+pub struct EpochDepositRequestedEvent {
+    pub vault_key: Pubkey,
+    pub epoch: u64,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct EpochRedeemRequestedEvent {
+    pub vault_key: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct EpochClosedEvent {
+    pub vault_key: Pubkey,
+    pub epoch: u64,
+    pub total_deposits: u64,
+    pub total_redemptions: u64,
+    pub deposit_shares: u64,
+    pub redeem_assets: u64,
+    pub share_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpochClaimedEvent {
+    pub vault_key: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+    pub assets: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct CircuitBreakerTrippedEvent {
+    pub vault_key: Pubkey,
+    pub last_price_per_share: u64,
+    pub price_per_share: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerResetEvent {
+    pub vault_key: Pubkey,
+    pub price_per_share: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct CircuitBreakerUpdatedEvent {
+    pub vault_key: Pubkey,
+    pub pps_drop_threshold: u64,
+}
+
+#[event]
+pub struct DefaultMaxLossUpdatedEvent {
+    pub vault_key: Pubkey,
+    pub default_max_loss: u64,
+}
+
+#[event]
+pub struct PositionMigratedEvent {
+    pub from_vault_key: Pubkey,
+    pub to_vault_key: Pubkey,
+    pub shares_burned: u64,
+    pub assets: u64,
+    pub shares_minted: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct WithdrawalSessionStartedEvent {
+    pub vault_key: Pubkey,
+    pub session_key: Pubkey,
+    pub shares: u64,
+    pub assets: u64,
+    pub max_loss: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct WithdrawalSessionProgressEvent {
+    pub vault_key: Pubkey,
+    pub session_key: Pubkey,
+    pub total_idle: u64,
+    pub loss: u64,
+}
+
+#[event]
+pub struct WithdrawalSessionCancelledEvent {
+    pub vault_key: Pubkey,
+    pub session_key: Pubkey,
+    pub shares: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub account_key: Pubkey,
+    pub version: u8,
+    pub old_len: u64,
+    pub new_len: u64,
+}
+
+#[event]
+pub struct StrategyProgramApprovedEvent {
+    pub vault_key: Pubkey,
+    pub program: Pubkey,
+}
+
+#[event]
+pub struct StrategyProgramRevokedEvent {
+    pub vault_key: Pubkey,
+    pub program: Pubkey,
+}
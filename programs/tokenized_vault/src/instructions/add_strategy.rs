@@ -4,11 +4,10 @@ use access_control::{
     program::AccessControl,
    state::{UserRole, Role}
 };
-use strategy::program::Strategy;
 
 use crate::errors::ErrorCode;
 use crate::constants::STRATEGY_DATA_SEED;
-use crate::state::{ StrategyData, Vault};
+use crate::state::{StrategyData, Vault};
 use crate::utils::strategy as strategy_utils;
 
 #[derive(Accounts)]
@@ -49,10 +48,20 @@ pub struct AddStrategy<'info> {
 
     pub access_control: Program<'info, AccessControl>,
     pub system_program: Program<'info, System>,
-    pub strategy_program: Program<'info, Strategy>,
+
+    /// CHECK: the built-in strategy program or one approved for the vault
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
 }
 
+/// Strategies of programs other than the built-in one need the vault
+/// strategy programs account in the remaining accounts
 pub fn handle_add_strategy(ctx: Context<AddStrategy>, max_debt: u64) -> Result<()> {
+    let strategy_program = ctx.accounts.strategy_program.key();
+    if strategy_program != strategy::ID {
+        strategy_utils::validate_strategy_program(&ctx.accounts.vault.key(), &strategy_program, ctx.remaining_accounts)?;
+    }
+
     let strategy_vault = strategy_utils::get_vault(&ctx.accounts.strategy.to_account_info())?;
 
     if strategy_vault != *ctx.accounts.vault.to_account_info().key {
@@ -60,5 +69,5 @@ pub fn handle_add_strategy(ctx: Context<AddStrategy>, max_debt: u64) -> Result<(
     }
 
     let strategy_data = &mut ctx.accounts.strategy_data;
-    strategy_data.init(ctx.accounts.strategy.key(), max_debt)
-}
\ No newline at end of file
+    strategy_data.init(ctx.accounts.strategy.key(), strategy_program, max_debt)
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::TokenAccount,
+};
+
+use crate::constants::{WITHDRAWAL_ESCROW_SEED, WITHDRAWAL_SESSION_SEED};
+use crate::events::WithdrawalSessionCancelledEvent;
+use crate::state::{Vault, WithdrawalSession};
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct CancelWithdraw<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_SESSION_SEED.as_bytes(),
+            vault.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        close = user,
+    )]
+    pub session: Box<Account<'info, WithdrawalSession>>,
+
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_ESCROW_SEED.as_bytes(),
+            vault.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+    )]
+    pub escrow_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Gives the escrowed shares back, funds already pulled to the idle stay in the vault
+/// and are released to other withdrawals
+pub fn handle_cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+    let shares = ctx.accounts.session.shares;
+    ctx.accounts.vault.load_mut()?.reserved_idle -= ctx.accounts.session.reserved_idle;
+
+    token::transfer_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_shares_account.to_account_info(),
+        ctx.accounts.user_shares_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        shares,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    token::close_account_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_shares_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    emit!(WithdrawalSessionCancelledEvent {
+        vault_key: ctx.accounts.vault.key(),
+        session_key: ctx.accounts.session.key(),
+        shares,
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::{EPOCH_REQUEST_SEED, EPOCH_SEED, EPOCH_SHARES_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::events::EpochClaimedEvent;
+use crate::state::{Epoch, EpochRequest, Vault};
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct ClaimEpoch<'info> {
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            EPOCH_SEED.as_bytes(),
+            vault.key().as_ref(),
+            epoch.index.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(
+        mut,
+        seeds = [
+            EPOCH_REQUEST_SEED.as_bytes(),
+            epoch.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        close = user,
+    )]
+    pub request: Box<Account<'info, EpochRequest>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_shares_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [EPOCH_SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub epoch_shares_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_claim_epoch(ctx: Context<ClaimEpoch>) -> Result<()> {
+    let epoch = &ctx.accounts.epoch;
+
+    if !epoch.is_settled {
+        return Err(ErrorCode::EpochNotSettled.into());
+    }
+
+    let shares = epoch.shares_for(&ctx.accounts.request);
+    let assets = epoch.assets_for(&ctx.accounts.request);
+
+    if shares > 0 {
+        token::transfer_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.epoch_shares_account.to_account_info(),
+            ctx.accounts.user_shares_account.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            shares,
+            &ctx.accounts.vault.load()?.seeds()
+        )?;
+    }
+
+    if assets > 0 {
+        token::transfer_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.vault_token_account.to_account_info(),
+            ctx.accounts.user_token_account.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            assets,
+            &ctx.accounts.vault.load()?.seeds()
+        )?;
+    }
+
+    emit!(EpochClaimedEvent {
+        vault_key: ctx.accounts.vault.key(),
+        epoch: epoch.index,
+        shares,
+        assets,
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
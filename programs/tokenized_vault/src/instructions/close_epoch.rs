@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, TokenAccount},
+};
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{EPOCH_SEED, EPOCH_SHARES_SEED, SHARES_SEED};
+use crate::errors::ErrorCode;
+use crate::events::EpochClosedEvent;
+use crate::state::{Epoch, Vault};
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct CloseEpoch<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            EPOCH_SEED.as_bytes(),
+            vault.key().as_ref(),
+            vault.load()?.current_epoch.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(
+        init,
+        seeds = [
+            EPOCH_SEED.as_bytes(),
+            vault.key().as_ref(),
+            (epoch.index + 1).to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = signer,
+        space = Epoch::LEN,
+    )]
+    pub next_epoch: Box<Account<'info, Epoch>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [EPOCH_SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub epoch_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::ReportingManager.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Settles every request of the current epoch at the same price per share.
+/// Should run right after `process_report` so the price reflects the latest NAV.
+pub fn handle_close_epoch(ctx: Context<CloseEpoch>) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    if !vault.is_epoch_based {
+        return Err(ErrorCode::NotEpochBasedVault.into());
+    }
+
+    if vault.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+
+    let total_deposits = ctx.accounts.epoch.total_deposits;
+    let total_redemptions = ctx.accounts.epoch.total_redemptions;
+
+    // both sides are priced before either is applied
+    let deposit_shares = vault.convert_to_shares(total_deposits);
+    let redeem_assets = vault.convert_to_underlying(total_redemptions);
+    let share_price = vault.convert_to_underlying(10u64.pow(ctx.accounts.shares_mint.decimals as u32));
+
+    if redeem_assets > vault.available_idle() + total_deposits {
+        return Err(ErrorCode::InsufficientFunds.into());
+    }
+    drop(vault);
+
+    // deposit shares wait in the escrow until claimed
+    if deposit_shares > 0 {
+        token::mint_to(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.shares_mint.to_account_info(),
+            ctx.accounts.epoch_shares_account.to_account_info(),
+            ctx.accounts.shares_mint.to_account_info(),
+            deposit_shares,
+            &ctx.accounts.vault.load()?.seeds_shares(),
+        )?;
+    }
+
+    if total_redemptions > 0 {
+        token::burn_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.shares_mint.to_account_info(),
+            ctx.accounts.epoch_shares_account.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            total_redemptions,
+            &ctx.accounts.vault.load()?.seeds(),
+        )?;
+    }
+
+    // redeemed assets stay in the vault token account until claimed
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.handle_deposit(total_deposits, deposit_shares);
+    vault.handle_withdraw(redeem_assets, total_redemptions);
+
+    ctx.accounts.epoch.settle(deposit_shares, redeem_assets)?;
+
+    let index = ctx.accounts.epoch.index;
+    ctx.accounts.next_epoch.init(vault.key, index + 1);
+    vault.current_epoch = index + 1;
+
+    emit!(EpochClosedEvent {
+        vault_key: vault.key,
+        epoch: index,
+        total_deposits,
+        total_redemptions,
+        deposit_shares,
+        redeem_assets,
+        share_price,
+        timestamp: ctx.accounts.epoch.settled_at,
+    });
+
+    Ok(())
+}
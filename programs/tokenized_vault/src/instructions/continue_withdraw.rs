@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::TokenAccount,
+};
+
+use crate::constants::{UNDERLYING_SEED, WITHDRAWAL_SESSION_SEED};
+use crate::errors::ErrorCode;
+use crate::events::WithdrawalSessionProgressEvent;
+use crate::instructions::withdraw::{parse_remaining, pull_from_strategies, AccountsMap};
+use crate::state::{Vault, WithdrawalSession};
+
+#[derive(Accounts)]
+pub struct ContinueWithdraw<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_SESSION_SEED.as_bytes(),
+            vault.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+    )]
+    pub session: Box<Account<'info, WithdrawalSession>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pulls from the strategies passed in this transaction into the vault idle
+/// and reserves it for the session, losses taken on the way are charged to the session
+pub fn handle_continue_withdraw<'info>(
+    ctx: Context<'_, '_, '_, 'info, ContinueWithdraw<'info>>,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    if ctx.accounts.vault.load()?.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+
+    let strategies_with_accounts = parse_remaining(&ctx.accounts.vault.key(), ctx.remaining_accounts, remaining_accounts_map)?;
+    let requested_assets = ctx.accounts.session.unreserved_assets();
+
+    let remaining_assets = pull_from_strategies(
+        &mut ctx.accounts.vault_token_account,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.vault,
+        requested_assets,
+        &strategies_with_accounts,
+    )?;
+
+    // the breaker tripped on a strategy loss, the pause has to be persisted
+    if ctx.accounts.vault.load()?.is_paused {
+        return Ok(());
+    }
+
+    let session = &mut ctx.accounts.session;
+    session.loss += requested_assets - remaining_assets;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let to_reserve = std::cmp::min(remaining_assets, vault.available_idle());
+    vault.reserved_idle += to_reserve;
+    session.reserved_idle += to_reserve;
+    drop(vault);
+
+    emit!(WithdrawalSessionProgressEvent {
+        vault_key: session.vault,
+        session_key: session.key(),
+        total_idle: ctx.accounts.vault.load()?.total_idle,
+        loss: session.loss,
+    });
+
+    Ok(())
+}
@@ -80,16 +80,59 @@ pub fn handle_deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
 }
 
 fn validate_deposit(ctx: &Context<Deposit>, amount: u64) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    validate_direct_deposit(
+        &vault,
+        amount,
+        ctx.accounts.user.key,
+        ctx.accounts.access_control.key,
+        ctx.remaining_accounts
+    )
+}
+
+/// Deposits straight into the vault shares, not through tranches or epochs
+pub(crate) fn validate_direct_deposit(
+    vault: &Vault,
+    amount: u64,
+    user: &Pubkey,
+    access_control: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if vault.is_tranched {
+        return Err(ErrorCode::TranchedVault.into());
+    }
+
+    if vault.is_epoch_based {
+        return Err(ErrorCode::EpochBasedVault.into());
+    }
+
+    validate_user_deposit(vault, amount, user, access_control, remaining_accounts)
+}
+
+pub(crate) fn validate_user_deposit(
+    vault: &Vault,
+    amount: u64,
+    user: &Pubkey,
+    access_control: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
     if amount == 0 {
         return Err(ErrorCode::ZeroValue.into());
     }
 
-    let vault = ctx.accounts.vault.load()?;
-
     if vault.is_shutdown {
         return Err(ErrorCode::VaultShutdown.into());
     }
 
+    if vault.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+
+    if !vault.is_deposit_window_open(Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::DepositWindowClosed.into());
+    }
+
     if amount < vault.min_user_deposit {
         return Err(ErrorCode::MinDepositNotReached.into());
     }
@@ -103,15 +146,14 @@ fn validate_deposit(ctx: &Context<Deposit>, amount: u64) -> Result<()> {
         let expected_roles_key = Pubkey::find_program_address(
             &[
                 USER_ROLE_SEED.as_bytes(),
-                ctx.accounts.user.key().as_ref(),
+                user.as_ref(),
                 Role::KYCVerified.to_seed().as_ref(),
             ],
-            ctx.accounts.access_control.key,
+            access_control,
         )
         .0;
 
-        let roles_acc_info: Option<&AccountInfo> = ctx
-            .remaining_accounts
+        let roles_acc_info: Option<&AccountInfo> = remaining_accounts
             .iter()
             .find(|account| account.key.eq(&expected_roles_key));
         if roles_acc_info.is_none() {
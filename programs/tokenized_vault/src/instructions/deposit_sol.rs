@@ -0,0 +1,125 @@
+use access_control::program::AccessControl;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{spl_token::native_mint, Mint, Token, TokenAccount};
+
+use crate::constants::{SHARES_SEED, TEMP_WSOL_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::events::VaultDepositEvent;
+use crate::instructions::deposit::validate_direct_deposit;
+use crate::state::Vault;
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct DepositSol<'info> {
+    #[account(
+        mut, 
+        constraint = vault.load()?.underlying_mint == native_mint.key() @ErrorCode::NotNativeMintVault
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init,
+        seeds = [TEMP_WSOL_SEED.as_bytes(), user.key().as_ref()],
+        bump,
+        payer = user,
+        token::mint = native_mint,
+        token::authority = user,
+    )]
+    pub temp_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = native_mint::ID)]
+    pub native_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_shares_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Wraps the lamports into a temporary wSOL account, deposits them 
+/// and closes the temporary account in the same instruction
+pub fn handle_deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    validate_direct_deposit(
+        &vault,
+        amount,
+        ctx.accounts.user.key,
+        ctx.accounts.access_control.key,
+        ctx.remaining_accounts
+    )?;
+    drop(vault);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.temp_wsol_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    token::sync_native(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.temp_wsol_account.to_account_info(),
+    )?;
+
+    let shares = ctx.accounts.vault.load()?.convert_to_shares(amount);
+
+    token::transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.temp_wsol_account.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+
+    token::mint_to(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        ctx.accounts.user_shares_account.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        shares,
+        &ctx.accounts.vault.load()?.seeds_shares(),
+    )?;
+
+    token::close_account(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.temp_wsol_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+    )?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.handle_deposit(amount, shares);
+
+    emit!(VaultDepositEvent {
+        vault_key: vault.key,
+        total_debt: vault.total_debt,
+        total_idle: vault.total_idle,
+        total_share: vault.total_shares(),
+        amount,
+        share: shares,
+        token_account: ctx.accounts.temp_wsol_account.key(),
+        share_account: ctx.accounts.user_shares_account.key(),
+        token_mint: ctx.accounts.native_mint.key(),
+        share_mint: ctx.accounts.shares_mint.key(),
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
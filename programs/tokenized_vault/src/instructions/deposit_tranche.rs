@@ -0,0 +1,128 @@
+use access_control::program::AccessControl;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::{
+    SHARES_SEED,
+    TRANCHES_POOL_SEED,
+    TRANCHES_SEED,
+    TRANCHE_SHARES_SEED,
+    UNDERLYING_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::TrancheDepositEvent;
+use crate::instructions::deposit::validate_user_deposit;
+use crate::state::{Tranche, Tranches, Vault};
+use crate::utils::token;
+
+#[derive(Accounts)]
+#[instruction(tranche: Tranche)]
+pub struct DepositTranche<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(mut, seeds = [TRANCHES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub tranches: Box<Account<'info, Tranches>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, seeds = [TRANCHES_POOL_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub pool_shares_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TRANCHE_SHARES_SEED.as_bytes(),
+            vault.key().as_ref(),
+            tranche.to_seed().as_ref()
+        ],
+        bump
+    )]
+    pub tranche_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_tranche_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub access_control: Program<'info, AccessControl>,
+}
+
+pub fn handle_deposit_tranche(ctx: Context<DepositTranche>, tranche: Tranche, amount: u64) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    if !vault.is_tranched {
+        return Err(ErrorCode::NotTranchedVault.into());
+    }
+
+    validate_user_deposit(
+        &vault,
+        amount,
+        ctx.accounts.user.key,
+        ctx.accounts.access_control.key,
+        ctx.remaining_accounts
+    )?;
+
+    let vault_shares = vault.convert_to_shares(amount);
+    drop(vault);
+
+    ctx.accounts.tranches.accrue()?;
+    let shares = ctx.accounts.tranches.convert_to_shares(tranche, amount, ctx.accounts.tranche_mint.supply)?;
+
+    token::transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+
+    token::mint_to(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        ctx.accounts.pool_shares_account.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        vault_shares,
+        &ctx.accounts.vault.load()?.seeds_shares(),
+    )?;
+
+    token::mint_to(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.tranche_mint.to_account_info(),
+        ctx.accounts.user_tranche_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        shares,
+        &ctx.accounts.vault.load()?.seeds(),
+    )?;
+
+    let tranches = &mut ctx.accounts.tranches;
+    tranches.handle_deposit(tranche, amount, vault_shares);
+
+    // senior deposits eat into the junior cushion
+    if tranche == Tranche::Senior {
+        tranches.check_subordination()?;
+    }
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.handle_deposit(amount, vault_shares);
+
+    emit!(TrancheDepositEvent {
+        vault_key: vault.key,
+        tranche: tranche as u8,
+        amount,
+        shares,
+        vault_shares,
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{ Mint, TokenAccount },
+};
+
+use crate::constants::{
+    MAX_BPS,
+    SHARES_SEED,
+    UNDERLYING_SEED,
+    WITHDRAWAL_ESCROW_SEED,
+    WITHDRAWAL_SESSION_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::{VaultShutDownEvent, VaultWithdrawlEvent};
+use crate::state::{Vault, WithdrawalSession};
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct FinalizeWithdraw<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_SESSION_SEED.as_bytes(),
+            vault.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        close = user,
+    )]
+    pub session: Box<Account<'info, WithdrawalSession>>,
+
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_ESCROW_SEED.as_bytes(),
+            vault.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+    )]
+    pub escrow_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Settles the session once the vault idle covers it, the loss check 
+/// is done on the total taken across all pages
+pub fn handle_finalize_withdraw(ctx: Context<FinalizeWithdraw>) -> Result<()> {
+    let session = &ctx.accounts.session;
+    let assets = session.assets;
+    let shares_to_burn = session.shares;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    if vault.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+
+    vault.reserved_idle -= session.reserved_idle;
+
+    // the price fixed at start is only an upper bound, losses the vault took since then
+    // are shared by the session. The losses already charged to it are added back to value the shares.
+    let current_assets = (shares_to_burn as u128 * (vault.total_funds() + session.loss) as u128 
+        / vault.total_shares() as u128) as u64;
+    let assets_to_transfer = std::cmp::min(session.requested_assets(), current_assets.saturating_sub(session.loss));
+
+    if vault.available_idle() < assets_to_transfer {
+        return Err(ErrorCode::InsufficientFunds.into());
+    }
+
+    if session.max_loss < MAX_BPS && assets - assets_to_transfer > (assets * session.max_loss) / MAX_BPS {
+        return Err(ErrorCode::TooMuchLoss.into());
+    }
+
+    // early exit penalty stays in the vault for the remaining depositors
+    let penalty = vault.exit_penalty(assets_to_transfer, timestamp)?;
+    let assets_to_transfer = assets_to_transfer - penalty;
+
+    vault.handle_withdraw(assets_to_transfer, shares_to_burn);
+
+    if vault.sunset(timestamp) {
+        emit!(VaultShutDownEvent {
+            vault_key: vault.key,
+            shutdown: true,
+        });
+    }
+    drop(vault);
+
+    token::burn_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        ctx.accounts.escrow_shares_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        shares_to_burn,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    token::close_account_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_shares_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    token::transfer_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        assets_to_transfer,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    let vault = ctx.accounts.vault.load()?;
+
+    emit!(VaultWithdrawlEvent {
+        vault_key: vault.key,
+        total_idle: vault.total_idle,
+        total_share: vault.total_shares(),
+        assets_to_transfer,
+        shares_to_burn,
+        token_account: ctx.accounts.user_token_account.key(),
+        share_account: ctx.accounts.escrow_shares_account.key(),
+        token_mint: ctx.accounts.vault_token_account.mint,
+        share_mint: ctx.accounts.shares_mint.key(),
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
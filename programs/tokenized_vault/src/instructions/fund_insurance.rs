@@ -0,0 +1,77 @@
+use access_control::program::AccessControl;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::{INSURANCE_SEED, SHARES_SEED, UNDERLYING_SEED};
+use crate::events::InsuranceFundedEvent;
+use crate::instructions::deposit::validate_user_deposit;
+use crate::state::Vault;
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct FundInsurance<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Account<'info, Mint>,
+
+    #[account(mut, seeds = [INSURANCE_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub insurance_shares_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub access_control: Program<'info, AccessControl>,
+}
+
+/// Sponsors can top up the insurance reserve, the funds are deposited 
+/// into the vault and the shares are kept by the reserve. The same limits 
+/// as for user deposits apply
+pub fn handle_fund_insurance(ctx: Context<FundInsurance>, amount: u64) -> Result<()> {
+    validate_user_deposit(
+        &*ctx.accounts.vault.load()?,
+        amount,
+        ctx.accounts.user.key,
+        ctx.accounts.access_control.key,
+        ctx.remaining_accounts
+    )?;
+
+    let shares = ctx.accounts.vault.load()?.convert_to_shares(amount);
+
+    token::transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+
+    token::mint_to(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        ctx.accounts.insurance_shares_account.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        shares,
+        &ctx.accounts.vault.load()?.seeds_shares(),
+    )?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.handle_deposit(amount, shares);
+
+    emit!(InsuranceFundedEvent {
+        vault_key: vault.key,
+        amount,
+        shares,
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{Role, UserRole}
+};
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::constants::{EPOCH_SEED, EPOCH_SHARES_SEED, SHARES_SEED};
+use crate::errors::ErrorCode;
+use crate::events::EpochsInitEvent;
+use crate::state::{Epoch, Vault};
+
+#[derive(Accounts)]
+pub struct InitEpochs<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init,
+        seeds = [
+            EPOCH_SEED.as_bytes(),
+            vault.key().as_ref(),
+            0u64.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = signer,
+        space = Epoch::LEN,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [EPOCH_SHARES_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+        payer = signer,
+        token::mint = shares_mint,
+        token::authority = vault,
+    )]
+    pub epoch_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Switches the vault to epoch mode, deposits and redemptions are queued 
+/// from now on and settled by `close_epoch`
+pub fn handle_init_epochs(ctx: Context<InitEpochs>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_tranched {
+        return Err(ErrorCode::TranchedVault.into());
+    }
+
+    ctx.accounts.epoch.init(vault.key, 0);
+
+    vault.is_epoch_based = true;
+    vault.current_epoch = 0;
+
+    emit!(EpochsInitEvent {
+        vault_key: vault.key,
+        epoch_shares_account: ctx.accounts.epoch_shares_account.key(),
+    });
+
+    Ok(())
+}
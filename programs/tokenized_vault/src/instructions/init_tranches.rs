@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{Role, UserRole}
+};
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::constants::{
+    SHARES_SEED,
+    TRANCHES_POOL_SEED,
+    TRANCHES_SEED,
+    TRANCHE_SHARES_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::TranchesInitEvent;
+use crate::state::{Tranche, Tranches, Vault};
+
+#[derive(Accounts)]
+pub struct InitTranches<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init,
+        seeds = [TRANCHES_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+        payer = signer,
+        space = Tranches::LEN,
+    )]
+    pub tranches: Box<Account<'info, Tranches>>,
+
+    #[account(seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [
+            TRANCHE_SHARES_SEED.as_bytes(),
+            vault.key().as_ref(),
+            Tranche::Senior.to_seed().as_ref()
+        ],
+        bump,
+        payer = signer,
+        mint::decimals = shares_mint.decimals,
+        mint::authority = vault,
+    )]
+    pub senior_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [
+            TRANCHE_SHARES_SEED.as_bytes(),
+            vault.key().as_ref(),
+            Tranche::Junior.to_seed().as_ref()
+        ],
+        bump,
+        payer = signer,
+        mint::decimals = shares_mint.decimals,
+        mint::authority = vault,
+    )]
+    pub junior_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [TRANCHES_POOL_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+        payer = signer,
+        token::mint = shares_mint,
+        token::authority = vault,
+    )]
+    pub pool_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Turns an empty vault into a tranched one, after this users can only
+/// enter through the senior and junior tranches
+pub fn handle_init_tranches(
+    ctx: Context<InitTranches>, 
+    senior_target_yield: u64, 
+    min_subordination: u64
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.total_shares != 0 {
+        return Err(ErrorCode::VaultNotEmpty.into());
+    }
+
+    if vault.is_epoch_based {
+        return Err(ErrorCode::EpochBasedVault.into());
+    }
+
+    ctx.accounts.tranches.init(
+        vault.key,
+        ctx.accounts.senior_mint.key(),
+        ctx.accounts.junior_mint.key(),
+        senior_target_yield,
+        min_subordination,
+    )?;
+
+    vault.is_tranched = true;
+
+    emit!(TranchesInitEvent {
+        vault_key: vault.key,
+        senior_mint: ctx.accounts.senior_mint.key(),
+        junior_mint: ctx.accounts.junior_mint.key(),
+        senior_target_yield,
+        min_subordination,
+    });
+
+    Ok(())
+}
@@ -22,6 +22,8 @@ use crate::constants::{
     SHARES_SEED, 
     SHARES_ACCOUNT_SEED, 
     CONFIG_SEED,
+    INSURANCE_SEED,
+    SHARES_DECIMALS,
 };
 use crate::state::*;
 
@@ -42,7 +44,7 @@ pub struct InitVaultShares<'info> {
         seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], 
         bump, 
         payer = signer, 
-        mint::decimals = 9, 
+        mint::decimals = SHARES_DECIMALS, 
         mint::authority = shares_mint,
     )]
     pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -60,6 +62,16 @@ pub struct InitVaultShares<'info> {
         token::authority = vault,
     )]
     pub shares_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init, 
+        seeds = [INSURANCE_SEED.as_bytes(), vault.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        token::mint = shares_mint,
+        token::authority = vault,
+    )]
+    pub insurance_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
     
     #[account(
         seeds = [
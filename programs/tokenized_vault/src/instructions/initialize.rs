@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{CONFIG_SEED, DISCRIMINATOR_LEN};
+use crate::constants::{CONFIG_SEED, CONFIG_VERSION};
 use crate::state::Config;
 
 #[derive(Accounts)]
@@ -10,7 +10,7 @@ pub struct Initialize<'info> {
         seeds = [CONFIG_SEED.as_bytes()], 
         bump,  
         payer = admin, 
-        space = DISCRIMINATOR_LEN + Config::INIT_SPACE,
+        space = Config::LEN,
     )]
     pub config: Account<'info, Config>,
 
@@ -21,6 +21,7 @@ pub struct Initialize<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handle_initialize(_ctx: Context<Initialize>) -> Result<()> {
+pub fn handle_initialize(ctx: Context<Initialize>) -> Result<()> {
+    ctx.accounts.config.version = CONFIG_VERSION;
     Ok(())
 }
@@ -0,0 +1,129 @@
+use access_control::program::AccessControl;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{ Mint, TokenAccount },
+};
+
+use crate::constants::{SHARES_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::events::PositionMigratedEvent;
+use crate::instructions::deposit::validate_direct_deposit;
+use crate::instructions::withdraw::{withdraw, AccountsMap, WithdrawAccounts};
+use crate::state::Vault;
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(mut)]
+    pub from_vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = to_vault.key() != from_vault.key() @ErrorCode::InvalidMigration,
+        constraint = to_vault.load()?.underlying_mint == from_vault.load()?.underlying_mint @ErrorCode::InvalidMigration
+    )]
+    pub to_vault: AccountLoader<'info, Vault>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), from_vault.key().as_ref()], bump)]
+    pub from_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), from_vault.key().as_ref()], bump)]
+    pub from_shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_from_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), to_vault.key().as_ref()], bump)]
+    pub to_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), to_vault.key().as_ref()], bump)]
+    pub to_shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_to_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub access_control: Program<'info, AccessControl>,
+}
+
+/// Redeems from one vault straight into the token account of another vault
+/// with the same underlying and deposits there, without touching the user's tokens
+pub fn handle_migrate_position<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigratePosition<'info>>,
+    shares: u64,
+    max_loss: Option<u64>,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    let assets = ctx.accounts.from_vault.load()?.convert_to_underlying(shares);
+    let remaining_accounts = ctx.remaining_accounts;
+    let accounts = ctx.accounts;
+
+    let shares_before = accounts.user_from_shares_account.amount;
+    let balance_before = accounts.to_vault_token_account.amount;
+
+    withdraw(
+        WithdrawAccounts {
+            vault: &accounts.from_vault,
+            vault_token_account: &mut accounts.from_vault_token_account,
+            shares_mint: accounts.from_shares_mint.to_account_info(),
+            user_shares_account: &accounts.user_from_shares_account,
+            user_token_account: accounts.to_vault_token_account.to_account_info(),
+            user: accounts.user.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+            remaining_accounts,
+        },
+        assets,
+        shares,
+        max_loss,
+        remaining_accounts_map
+    )?;
+
+    accounts.user_from_shares_account.reload()?;
+    accounts.to_vault_token_account.reload()?;
+
+    let shares_burned = shares_before - accounts.user_from_shares_account.amount;
+    let amount = accounts.to_vault_token_account.amount - balance_before;
+
+    // nothing was redeemed when the circuit breaker of the source vault tripped
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let to_vault = accounts.to_vault.load()?;
+    validate_direct_deposit(
+        &to_vault,
+        amount,
+        accounts.user.key,
+        accounts.access_control.key,
+        remaining_accounts
+    )?;
+
+    let new_shares = to_vault.convert_to_shares(amount);
+    drop(to_vault);
+
+    token::mint_to(
+        accounts.token_program.to_account_info(),
+        accounts.to_shares_mint.to_account_info(),
+        accounts.user_to_shares_account.to_account_info(),
+        accounts.to_shares_mint.to_account_info(),
+        new_shares,
+        &accounts.to_vault.load()?.seeds_shares(),
+    )?;
+
+    accounts.to_vault.load_mut()?.handle_deposit(amount, new_shares);
+
+    emit!(PositionMigratedEvent {
+        from_vault_key: accounts.from_vault.key(),
+        to_vault_key: accounts.to_vault.key(),
+        shares_burned,
+        assets: amount,
+        shares_minted: new_shares,
+        authority: accounts.user.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,249 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::constants::{
+    CONFIG_SEED,
+    CONFIG_VERSION,
+    INSURANCE_SEED,
+    SHARES_SEED,
+    STRATEGY_DATA_SEED,
+    STRATEGY_DATA_VERSION,
+    VAULT_VERSION,
+};
+use crate::errors::ErrorCode;
+use crate::events::AccountMigratedEvent;
+use crate::state::{Config, StrategyData, Vault};
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    /// CHECK: older layouts can't be loaded, the discriminator is checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// vaults created before the insurance fund have no insurance shares account yet
+    #[account(
+        init_if_needed, 
+        seeds = [INSURANCE_SEED.as_bytes(), vault.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        token::mint = shares_mint,
+        token::authority = vault,
+    )]
+    pub insurance_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateStrategyData<'info> {
+    /// CHECK: only used for the strategy data seeds, the vault may not be migrated yet
+    #[account(owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: can be any strategy
+    #[account()]
+    pub strategy: UncheckedAccount<'info>,
+
+    /// CHECK: older layouts can't be deserialized, the discriminator is checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            STRATEGY_DATA_SEED.as_bytes(),
+            vault.key().as_ref(),
+            strategy.key().as_ref()
+        ],
+        bump,
+    )]
+    pub strategy_data: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: older layouts can't be deserialized, the discriminator is checked in the handler
+    #[account(mut, owner = crate::ID, seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Reallocs a vault created with an older layout, the added fields start 
+/// zeroed which is their default. Also creates the insurance shares account
+/// that older vaults were initialized without
+pub fn handle_migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let old_len = grow(
+        &vault_info, 
+        &Vault::DISCRIMINATOR, 
+        Vault::LEN, 
+        &ctx.accounts.signer, 
+        &ctx.accounts.system_program
+    )?;
+
+    // the vault is packed zero copy and the version is followed by 8 words,
+    // the fields carved from the reserved space and what is left of it
+    let version_offset = Vault::LEN - std::mem::size_of::<[u64; 8]>() - 1;
+    let mut data = vault_info.try_borrow_mut_data()?;
+    let version = &mut data[version_offset];
+
+    if *version >= VAULT_VERSION {
+        return Err(ErrorCode::AlreadyMigrated.into());
+    }
+
+    *version = VAULT_VERSION;
+
+    emit!(AccountMigratedEvent {
+        account_key: vault_info.key(),
+        version: VAULT_VERSION,
+        old_len: old_len as u64,
+        new_len: Vault::LEN as u64,
+    });
+
+    Ok(())
+}
+
+pub fn handle_migrate_strategy_data(ctx: Context<MigrateStrategyData>) -> Result<()> {
+    let data_info = ctx.accounts.strategy_data.to_account_info();
+    let old_len = grow(
+        &data_info, 
+        &StrategyData::DISCRIMINATOR, 
+        StrategyData::LEN, 
+        &ctx.accounts.signer, 
+        &ctx.accounts.system_program
+    )?;
+
+    let mut data = data_info.try_borrow_mut_data()?;
+    let mut strategy_data = StrategyData::try_deserialize(&mut &data[..])?;
+
+    if strategy_data.version >= STRATEGY_DATA_VERSION {
+        return Err(ErrorCode::AlreadyMigrated.into());
+    }
+
+    strategy_data.strategy_program = *ctx.accounts.strategy.owner;
+    strategy_data.version = STRATEGY_DATA_VERSION;
+    strategy_data.try_serialize(&mut &mut data[..])?;
+
+    emit!(AccountMigratedEvent {
+        account_key: data_info.key(),
+        version: STRATEGY_DATA_VERSION,
+        old_len: old_len as u64,
+        new_len: StrategyData::LEN as u64,
+    });
+
+    Ok(())
+}
+
+pub fn handle_migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    let old_len = grow(
+        &config_info, 
+        &Config::DISCRIMINATOR, 
+        Config::LEN, 
+        &ctx.accounts.signer, 
+        &ctx.accounts.system_program
+    )?;
+
+    let mut data = config_info.try_borrow_mut_data()?;
+    let mut config = Config::try_deserialize(&mut &data[..])?;
+
+    if config.version >= CONFIG_VERSION {
+        return Err(ErrorCode::AlreadyMigrated.into());
+    }
+
+    config.version = CONFIG_VERSION;
+    config.try_serialize(&mut &mut data[..])?;
+
+    emit!(AccountMigratedEvent {
+        account_key: config_info.key(),
+        version: CONFIG_VERSION,
+        old_len: old_len as u64,
+        new_len: Config::LEN as u64,
+    });
+
+    Ok(())
+}
+
+fn grow<'info>(
+    account_info: &AccountInfo<'info>,
+    discriminator: &[u8],
+    new_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<usize> {
+    let old_len = account_info.data_len();
+
+    if old_len < discriminator.len() || &account_info.try_borrow_data()?[..discriminator.len()] != discriminator {
+        return Err(ErrorCode::InvalidAccountType.into());
+    }
+
+    if old_len < new_len {
+        account_utils::realloc(
+            account_info,
+            &payer.to_account_info(),
+            &system_program.to_account_info(),
+            new_len
+        )?;
+    }
+
+    Ok(old_len)
+}
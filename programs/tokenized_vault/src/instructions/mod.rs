@@ -1,25 +1,71 @@
 pub mod add_strategy;
+pub mod cancel_withdraw;
+pub mod claim_epoch;
+pub mod close_epoch;
 pub mod close_vault;
+pub mod continue_withdraw;
 pub mod deposit;
+pub mod deposit_sol;
+pub mod deposit_tranche;
+pub mod finalize_withdraw;
+pub mod fund_insurance;
 pub mod initialize;
+pub mod init_epochs;
 pub mod init_vault;
 pub mod init_vault_shares;
+pub mod init_tranches;
+pub mod migrate_position;
+pub mod migrate_vault;
+pub mod override_health_check;
 pub mod process_report;
 pub mod remove_strategy;
+pub mod request_deposit;
+pub mod request_redeem;
+pub mod reset_circuit_breaker;
 pub mod setters;
 pub mod shutdown_vault;
+pub mod start_withdraw;
+pub mod strategy_programs;
+pub mod sunset_vault;
 pub mod update_debt;
+pub mod views;
 pub mod withdraw;
+pub mod withdraw_insurance;
+pub mod withdraw_sol;
+pub mod withdraw_tranche;
 
 pub use add_strategy::*;
+pub use cancel_withdraw::*;
+pub use claim_epoch::*;
+pub use close_epoch::*;
 pub use close_vault::*;
+pub use continue_withdraw::*;
 pub use deposit::*;
+pub use deposit_sol::*;
+pub use deposit_tranche::*;
+pub use finalize_withdraw::*;
+pub use fund_insurance::*;
 pub use initialize::*;
+pub use init_epochs::*;
 pub use init_vault::*;
 pub use init_vault_shares::*;
+pub use init_tranches::*;
+pub use migrate_position::*;
+pub use migrate_vault::*;
+pub use override_health_check::*;
 pub use process_report::*;
 pub use remove_strategy::*;
+pub use request_deposit::*;
+pub use request_redeem::*;
+pub use reset_circuit_breaker::*;
 pub use setters::*;
 pub use shutdown_vault::*;
+pub use start_withdraw::*;
+pub use strategy_programs::*;
+pub use sunset_vault::*;
 pub use update_debt::*;
+pub use views::*;
 pub use withdraw::*;
+pub use withdraw_insurance::*;
+pub use withdraw_sol::*;
+pub use withdraw_tranche::*;
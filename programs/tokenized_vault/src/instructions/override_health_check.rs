@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::STRATEGY_DATA_SEED;
+use crate::events::StrategyHealthCheckOverriddenEvent;
+use crate::state::{StrategyData, Vault};
+
+#[derive(Accounts)]
+pub struct OverrideHealthCheck<'info> {
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    /// CHECK: can be any strategy
+    #[account()]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_DATA_SEED.as_bytes(),
+            vault.key().as_ref(),
+            strategy.key().as_ref()
+        ],
+        bump,
+    )]
+    pub strategy_data: Account<'info, StrategyData>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::HealthCheckOverrider.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+/// Lets the next `process_report` of the strategy skip the health check,
+/// both the strategy limits and the max gain/loss of the accountant
+pub fn handle_override_health_check(ctx: Context<OverrideHealthCheck>) -> Result<()> {
+    ctx.accounts.strategy_data.skip_health_check = true;
+
+    emit!(StrategyHealthCheckOverriddenEvent {
+        vault_key: ctx.accounts.vault.key(),
+        strategy_key: ctx.accounts.strategy.key(),
+        authority: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -9,9 +9,24 @@ use access_control::{
     state::{UserRole, Role}
 };
 
-use crate::constants::{ MAX_BPS_EXTENDED, SHARES_ACCOUNT_SEED, SHARES_SEED, STRATEGY_DATA_SEED};
-use crate::events::StrategyReportedEvent;
-use crate::state::{Vault, StrategyData};
+use crate::constants::{
+    INSURANCE_SEED,
+    MAX_BPS,
+    MAX_BPS_EXTENDED,
+    SHARES_ACCOUNT_SEED,
+    SHARES_SEED,
+    STRATEGY_DATA_SEED,
+    TRANCHES_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::{
+    CircuitBreakerTrippedEvent,
+    InsuranceFundedEvent,
+    InsuranceLossCoveredEvent,
+    StrategyReportedEvent,
+    TranchesReportedEvent,
+};
+use crate::state::{Vault, StrategyData, Tranches};
 use crate::utils::{accountant, strategy, token};
 
 #[derive(Accounts)]
@@ -40,6 +55,9 @@ pub struct ProcessReport<'info> {
     #[account(mut, seeds = [SHARES_ACCOUNT_SEED.as_bytes(), vault.key().as_ref()], bump)]
     pub vault_shares_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(mut, seeds = [INSURANCE_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub insurance_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
     /// CHECK:
     #[account(mut, address = vault.load()?.accountant)]
     pub accountant: UncheckedAccount<'info>,
@@ -70,12 +88,12 @@ pub struct ProcessReport<'info> {
 }
 
 pub fn handle_process_report(ctx: Context<ProcessReport>) -> Result<()> {
+    ctx.accounts.strategy_data.check_strategy_program(&ctx.accounts.strategy)?;
     let strategy_assets = strategy::get_total_assets(&ctx.accounts.strategy)?;
     let strategy = &ctx.accounts.strategy;
 
     let mut profit: u64 = 0;
     let mut loss: u64 = 0;
-    let mut fee_shares: u64 = 0;
 
     burn_unlocked_shares(&ctx)?;
     ctx.accounts.vault_shares_token_account.reload()?;
@@ -84,21 +102,61 @@ pub fn handle_process_report(ctx: Context<ProcessReport>) -> Result<()> {
 
     if strategy_assets > current_debt {
         profit = strategy_assets - current_debt;
-        let (total_fees, _) = accountant::report(&ctx.accounts.accountant, profit, 0)?;
-        fee_shares = ctx.accounts.vault.load()?.convert_to_shares(total_fees);
+    } else {
+        loss = current_debt - strategy_assets;
+    }
 
-        handle_profit(&ctx, profit, total_fees)?;
+    // the override covers the vault limits and the accountant's max gain/loss alike
+    if !ctx.accounts.strategy_data.take_health_check_override() {
+        ctx.accounts.strategy_data.check_health(profit, loss)?;
+        accountant::check_health(
+            &ctx.accounts.accountant,
+            ctx.accounts.vault.key(),
+            profit,
+            loss,
+            current_debt,
+        )?;
+    }
 
-        if fee_shares > 0 {
-            issue_fee_shares(&ctx, fee_shares)?;
-        }
+    let (total_fees, _) = accountant::report(
+        &ctx.accounts.accountant,
+        ctx.accounts.vault.key(),
+        profit,
+        loss,
+        current_debt,
+        get_report_duration(ctx.accounts.strategy_data.last_update)?,
+    )?;
+    let insurance_cut = get_insurance_cut(&ctx.accounts.vault, profit, total_fees)?;
+    let fee_shares = ctx.accounts.vault.load()?.convert_to_shares(total_fees);
+    let insurance_shares = ctx.accounts.vault.load()?.convert_to_shares(insurance_cut);
+
+    if profit > 0 {
+        handle_profit(&ctx, profit, total_fees + insurance_cut)?;
     } else {
-        loss = current_debt - strategy_assets;
         handle_loss(&ctx, loss)?;
     }
 
+    if fee_shares > 0 {
+        issue_fee_shares(&ctx, fee_shares)?;
+    }
+
+    if insurance_shares > 0 {
+        issue_insurance_shares(&ctx, insurance_cut, insurance_shares)?;
+    }
+
     ctx.accounts.strategy_data.update_strategy_current_debt(strategy_assets)?;
 
+    // a tripped breaker keeps the pre-loss price until a guardian resets it
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    if !vault.is_paused {
+        vault.last_price_per_share = vault.price_per_share();
+    }
+    drop(vault);
+
+    if ctx.accounts.vault.load()?.is_tranched {
+        split_tranches(&ctx)?;
+    }
+
     emit!(StrategyReportedEvent {
         strategy_key: strategy.key(),
         gain: profit,
@@ -128,13 +186,45 @@ fn issue_fee_shares(ctx: &Context<ProcessReport>, fee_shares: u64) -> Result<u64
     Ok(fee_shares)
 }
 
+fn issue_insurance_shares(ctx: &Context<ProcessReport>, amount: u64, shares: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    token::mint_to(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        ctx.accounts.insurance_shares_account.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        shares,
+        &vault.seeds_shares()
+    )?;
+
+    vault.total_shares += shares;
+
+    emit!(InsuranceFundedEvent {
+        vault_key: vault.key,
+        amount,
+        shares,
+        authority: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+fn get_insurance_cut(vault_loader: &AccountLoader<Vault>, profit: u64, fees: u64) -> Result<u64> {
+    let vault = vault_loader.load()?;
+    let cut = (profit as u128 * vault.insurance_fee as u128 / MAX_BPS as u128) as u64;
+
+    // fees are taken first, the reserve never gets more than what is left
+    Ok(std::cmp::min(cut, profit.saturating_sub(fees)))
+}
+
 fn handle_profit(ctx: &Context<ProcessReport>, profit: u64, fees: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault.load_mut()?;
   
     let mut shares_to_lock = 0;
     if vault.profit_max_unlock_time != 0 {
         // we don't lock fee shares
-        let amount_to_lock = profit - fees;
+        let amount_to_lock = profit.saturating_sub(fees);
         shares_to_lock = vault.convert_to_shares(amount_to_lock);
 
         let curr_locked_shares = ctx.accounts.vault_shares_token_account.amount;
@@ -197,10 +287,45 @@ fn handle_loss(ctx: &Context<ProcessReport>, loss: u64) -> Result<()> {
         &ctx.accounts.vault.load()?.seeds(),
     )?;
 
+    // the insurance reserve covers what the locked profit could not
+    let insurance_shares_to_burn = std::cmp::min(
+        ctx.accounts.insurance_shares_account.amount,
+        loss_shares - shares_to_burn
+    );
+
+    if insurance_shares_to_burn > 0 {
+        token::burn_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.shares_mint.to_account_info(),
+            ctx.accounts.insurance_shares_account.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            insurance_shares_to_burn,
+            &ctx.accounts.vault.load()?.seeds(),
+        )?;
+
+        emit!(InsuranceLossCoveredEvent {
+            vault_key: ctx.accounts.vault.key(),
+            strategy_key: ctx.accounts.strategy.key(),
+            loss,
+            shares_burned: insurance_shares_to_burn,
+        });
+    }
+
     let vault = &mut ctx.accounts.vault.load_mut()?;
     vault.total_debt -= loss;
+    vault.total_shares -= insurance_shares_to_burn;
     vault.last_profit_update = get_timestamp()?;
 
+    let total_funds = vault.total_funds();
+    if vault.check_circuit_breaker(total_funds) {
+        emit!(CircuitBreakerTrippedEvent {
+            vault_key: vault.key,
+            last_price_per_share: vault.last_price_per_share,
+            price_per_share: vault.price_per_share(),
+            timestamp: get_timestamp()? as i64,
+        });
+    }
+
     Ok(())
 }
 
@@ -250,6 +375,46 @@ fn get_shares_to_burn(vault_loader: &AccountLoader<Vault>, total_locked: u64) ->
     Ok(shares_to_burn)
 }
 
+/// Tranches account is passed in remaining accounts, only tranched vaults need it
+fn split_tranches(ctx: &Context<ProcessReport>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let expected_tranches_key = Pubkey::find_program_address(
+        &[TRANCHES_SEED.as_bytes(), vault_key.as_ref()],
+        ctx.program_id,
+    )
+    .0;
+
+    let tranches_acc_info = ctx
+        .remaining_accounts
+        .iter()
+        .find(|account| account.key.eq(&expected_tranches_key))
+        .ok_or(ErrorCode::TranchesAccountMissing)?;
+
+    let mut data = tranches_acc_info.try_borrow_mut_data()?;
+    let mut tranches = Tranches::try_deserialize(&mut &data[..])?;
+
+    let total_assets = ctx.accounts.vault.load()?.convert_to_underlying(tranches.pool_shares);
+    tranches.update_split(total_assets)?;
+    tranches.try_serialize(&mut &mut data[..])?;
+
+    emit!(TranchesReportedEvent {
+        vault_key,
+        senior_assets: tranches.senior_assets,
+        junior_assets: tranches.junior_assets,
+        timestamp: tranches.last_update,
+    });
+
+    Ok(())
+}
+
+fn get_report_duration(last_update: i64) -> Result<u64> {
+    if last_update == 0 {
+        return Ok(0);
+    }
+
+    Ok(get_timestamp()?.saturating_sub(last_update as u64))
+}
+
 fn get_timestamp() -> Result<u64> {
     Ok(Clock::get()?.unix_timestamp as u64)
 }
\ No newline at end of file
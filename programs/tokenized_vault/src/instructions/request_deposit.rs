@@ -0,0 +1,104 @@
+use access_control::program::AccessControl;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::{EPOCH_REQUEST_SEED, EPOCH_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::events::EpochDepositRequestedEvent;
+use crate::instructions::deposit::validate_user_deposit;
+use crate::state::{Epoch, EpochRequest, Vault};
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct RequestDeposit<'info> {
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            EPOCH_SEED.as_bytes(),
+            vault.key().as_ref(),
+            vault.load()?.current_epoch.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            EPOCH_REQUEST_SEED.as_bytes(),
+            epoch.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        payer = user,
+        space = EpochRequest::LEN,
+    )]
+    pub request: Box<Account<'info, EpochRequest>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Queued underlying sits in the vault token account but is not idle
+/// until the epoch is closed
+pub fn handle_request_deposit(ctx: Context<RequestDeposit>, amount: u64) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    if !vault.is_epoch_based {
+        return Err(ErrorCode::NotEpochBasedVault.into());
+    }
+
+    validate_user_deposit(
+        &vault,
+        amount,
+        ctx.accounts.user.key,
+        ctx.accounts.access_control.key,
+        ctx.remaining_accounts
+    )?;
+
+    // queued deposits count against the limit, close_epoch mints them without checking it
+    if amount + ctx.accounts.epoch.total_deposits > vault.max_deposit() {
+        return Err(ErrorCode::ExceedDepositLimit.into());
+    }
+
+    let vault_key = vault.key;
+    drop(vault);
+
+    token::transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+
+    let request = &mut ctx.accounts.request;
+    request.epoch = ctx.accounts.epoch.key();
+    request.user = ctx.accounts.user.key();
+    request.deposit_amount += amount;
+
+    let epoch = &mut ctx.accounts.epoch;
+    epoch.total_deposits += amount;
+
+    emit!(EpochDepositRequestedEvent {
+        vault_key,
+        epoch: epoch.index,
+        amount,
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
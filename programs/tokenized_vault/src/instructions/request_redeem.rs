@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::{EPOCH_REQUEST_SEED, EPOCH_SEED, EPOCH_SHARES_SEED};
+use crate::errors::ErrorCode;
+use crate::events::EpochRedeemRequestedEvent;
+use crate::state::{Epoch, EpochRequest, Vault};
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct RequestRedeem<'info> {
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            EPOCH_SEED.as_bytes(),
+            vault.key().as_ref(),
+            vault.load()?.current_epoch.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            EPOCH_REQUEST_SEED.as_bytes(),
+            epoch.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        payer = user,
+        space = EpochRequest::LEN,
+    )]
+    pub request: Box<Account<'info, EpochRequest>>,
+
+    #[account(mut)]
+    pub user_shares_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [EPOCH_SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub epoch_shares_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Shares are escrowed until the epoch is closed and burned at the epoch price
+pub fn handle_request_redeem(ctx: Context<RequestRedeem>, shares: u64) -> Result<()> {
+    if shares == 0 {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+
+    let vault = ctx.accounts.vault.load()?;
+
+    if !vault.is_epoch_based {
+        return Err(ErrorCode::NotEpochBasedVault.into());
+    }
+
+    if vault.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+
+    if ctx.accounts.user_shares_account.amount < shares {
+        return Err(ErrorCode::InsufficientShares.into());
+    }
+
+    let vault_key = vault.key;
+    drop(vault);
+
+    token::transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_shares_account.to_account_info(),
+        ctx.accounts.epoch_shares_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        shares,
+    )?;
+
+    let request = &mut ctx.accounts.request;
+    request.epoch = ctx.accounts.epoch.key();
+    request.user = ctx.accounts.user.key();
+    request.redeem_shares += shares;
+
+    let epoch = &mut ctx.accounts.epoch;
+    epoch.total_redemptions += shares;
+
+    emit!(EpochRedeemRequestedEvent {
+        vault_key,
+        epoch: epoch.index,
+        shares,
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::errors::ErrorCode;
+use crate::events::CircuitBreakerResetEvent;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::Guardian.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+/// After review the guardian unpauses the vault and accepts the current price per share
+pub fn handle_reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if !vault.is_paused {
+        return Err(ErrorCode::VaultNotPaused.into());
+    }
+
+    vault.is_paused = false;
+    vault.last_price_per_share = vault.price_per_share();
+
+    emit!(CircuitBreakerResetEvent {
+        vault_key: vault.key,
+        price_per_share: vault.last_price_per_share,
+        authority: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
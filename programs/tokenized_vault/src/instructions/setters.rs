@@ -5,9 +5,16 @@ use access_control::{
     state::{UserRole, Role}
 };
 
-use crate::events::VaultUpdateDepositLimitEvent;
+use crate::constants::{MAX_BPS, STRATEGY_DATA_SEED};
+use crate::events::{
+    CircuitBreakerUpdatedEvent,
+    DefaultMaxLossUpdatedEvent,
+    InsuranceConfigUpdatedEvent,
+    StrategyHealthCheckUpdatedEvent,
+    VaultUpdateDepositLimitEvent,
+};
 use crate::errors::ErrorCode;
-use crate::state::Vault;
+use crate::state::{InsuranceConfig, StrategyData, Vault};
 
 #[derive(Accounts)]
 pub struct SetDepositLimit<'info> {
@@ -31,6 +38,109 @@ pub struct SetDepositLimit<'info> {
     pub access_control: Program<'info, AccessControl>
 }
 
+#[derive(Accounts)]
+pub struct SetDefaultMaxLoss<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+#[derive(Accounts)]
+pub struct SetInsuranceConfig<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+#[derive(Accounts)]
+pub struct SetCircuitBreaker<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+#[derive(Accounts)]
+pub struct SetStrategyHealthCheck<'info> {
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    /// CHECK: can be any strategy
+    #[account()]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_DATA_SEED.as_bytes(),
+            vault.key().as_ref(),
+            strategy.key().as_ref()
+        ],
+        bump,
+    )]
+    pub strategy_data: Account<'info, StrategyData>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
 pub fn handle_set_deposit_limit(ctx: Context<SetDepositLimit>, amount: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault.load_mut()?;
 
@@ -47,3 +157,79 @@ pub fn handle_set_deposit_limit(ctx: Context<SetDepositLimit>, amount: u64) -> R
 
     Ok(())
 }
+
+pub fn handle_set_default_max_loss(ctx: Context<SetDefaultMaxLoss>, max_loss: u64) -> Result<()> {
+    if max_loss > MAX_BPS {
+        return Err(ErrorCode::InvalidMaxLoss.into());
+    }
+
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+    vault.default_max_loss = max_loss;
+
+    emit!(DefaultMaxLossUpdatedEvent {
+        vault_key: vault.key,
+        default_max_loss: max_loss,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_insurance_config(ctx: Context<SetInsuranceConfig>, config: InsuranceConfig) -> Result<()> {
+    if config.fee > MAX_BPS {
+        return Err(ErrorCode::InvalidInsuranceFee.into());
+    }
+
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    vault.insurance_fee = config.fee;
+    vault.insurance_target = config.target;
+    vault.insurance_owner = config.owner;
+
+    emit!(InsuranceConfigUpdatedEvent {
+        vault_key: vault.key,
+        fee: config.fee,
+        target: config.target,
+        owner: config.owner,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_circuit_breaker(ctx: Context<SetCircuitBreaker>, pps_drop_threshold: u64) -> Result<()> {
+    if pps_drop_threshold > MAX_BPS {
+        return Err(ErrorCode::InvalidCircuitBreakerThreshold.into());
+    }
+
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+    vault.pps_drop_threshold = pps_drop_threshold;
+
+    emit!(CircuitBreakerUpdatedEvent {
+        vault_key: vault.key,
+        pps_drop_threshold,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_strategy_health_check(
+    ctx: Context<SetStrategyHealthCheck>, 
+    max_profit_bps: u64, 
+    max_loss_bps: u64
+) -> Result<()> {
+    if max_loss_bps > MAX_BPS {
+        return Err(ErrorCode::InvalidHealthCheckLimit.into());
+    }
+
+    let strategy_data = &mut ctx.accounts.strategy_data;
+    strategy_data.max_profit_bps = max_profit_bps;
+    strategy_data.max_loss_bps = max_loss_bps;
+
+    emit!(StrategyHealthCheckUpdatedEvent {
+        vault_key: ctx.accounts.vault.key(),
+        strategy_key: ctx.accounts.strategy.key(),
+        max_profit_bps,
+        max_loss_bps,
+    });
+
+    Ok(())
+}
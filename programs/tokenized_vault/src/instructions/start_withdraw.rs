@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{ Mint, TokenAccount },
+};
+
+use crate::constants::{SHARES_SEED, WITHDRAWAL_ESCROW_SEED, WITHDRAWAL_SESSION_SEED};
+use crate::errors::ErrorCode;
+use crate::events::WithdrawalSessionStartedEvent;
+use crate::state::{Vault, WithdrawalSession};
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct StartWithdraw<'info> {
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init,
+        seeds = [
+            WITHDRAWAL_SESSION_SEED.as_bytes(),
+            vault.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        payer = user,
+        space = WithdrawalSession::LEN,
+    )]
+    pub session: Box<Account<'info, WithdrawalSession>>,
+
+    #[account(seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [
+            WITHDRAWAL_ESCROW_SEED.as_bytes(),
+            vault.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        payer = user,
+        token::mint = shares_mint,
+        token::authority = vault,
+    )]
+    pub escrow_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrows the shares and fixes the assets they are worth, strategies are 
+/// then drained with `continue_withdraw` and the user paid in `finalize_withdraw`
+pub fn handle_start_withdraw(ctx: Context<StartWithdraw>, shares: u64, max_loss: Option<u64>) -> Result<()> {
+    if shares == 0 {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+
+    let vault = ctx.accounts.vault.load()?;
+
+    if vault.is_epoch_based {
+        return Err(ErrorCode::EpochBasedVault.into());
+    }
+
+    if vault.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+
+    if ctx.accounts.user_shares_account.amount < shares {
+        return Err(ErrorCode::InsufficientShares.into());
+    }
+
+    let max_loss = vault.resolve_max_loss(max_loss)?;
+    let assets = vault.convert_to_underlying(shares);
+    drop(vault);
+
+    token::transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_shares_account.to_account_info(),
+        ctx.accounts.escrow_shares_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        shares,
+    )?;
+
+    let session = &mut ctx.accounts.session;
+    session.vault = ctx.accounts.vault.key();
+    session.user = ctx.accounts.user.key();
+    session.shares = shares;
+    session.assets = assets;
+    session.max_loss = max_loss;
+    session.started_at = Clock::get()?.unix_timestamp;
+
+    emit!(WithdrawalSessionStartedEvent {
+        vault_key: session.vault,
+        session_key: session.key(),
+        shares,
+        assets,
+        max_loss,
+        authority: session.user,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::STRATEGY_PROGRAMS_SEED;
+use crate::errors::ErrorCode;
+use crate::events::{StrategyProgramApprovedEvent, StrategyProgramRevokedEvent};
+use crate::state::{StrategyPrograms, Vault};
+
+#[derive(Accounts)]
+pub struct ApproveStrategyProgram<'info> {
+    #[account(
+        init_if_needed,
+        seeds = [STRATEGY_PROGRAMS_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+        payer = signer,
+        space = StrategyPrograms::LEN
+    )]
+    pub strategy_programs: Account<'info, StrategyPrograms>,
+
+    pub vault: AccountLoader<'info, Vault>,
+
+    /// CHECK: any program implementing the strategy interface
+    #[account(
+        executable,
+        constraint = strategy_program.key() != strategy::ID @ ErrorCode::InvalidStrategyProgram
+    )]
+    pub strategy_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeStrategyProgram<'info> {
+    #[account(
+        mut,
+        seeds = [STRATEGY_PROGRAMS_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+    )]
+    pub strategy_programs: Account<'info, StrategyPrograms>,
+
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+/// Allows strategies owned by the program to be added to the vault
+pub fn handle_approve_strategy_program(ctx: Context<ApproveStrategyProgram>) -> Result<()> {
+    let strategy_programs = &mut ctx.accounts.strategy_programs;
+    strategy_programs.vault = ctx.accounts.vault.key();
+    strategy_programs.approve(ctx.accounts.strategy_program.key())?;
+
+    emit!(StrategyProgramApprovedEvent {
+        vault_key: ctx.accounts.vault.key(),
+        program: ctx.accounts.strategy_program.key(),
+    });
+
+    Ok(())
+}
+
+/// Strategies of the program can no longer be added or get deposits, withdrawals keep working
+pub fn handle_revoke_strategy_program(ctx: Context<RevokeStrategyProgram>, program: Pubkey) -> Result<()> {
+    ctx.accounts.strategy_programs.revoke(&program)?;
+
+    emit!(StrategyProgramRevokedEvent {
+        vault_key: ctx.accounts.vault.key(),
+        program,
+    });
+
+    Ok(())
+}
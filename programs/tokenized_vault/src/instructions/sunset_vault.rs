@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::VaultShutDownEvent;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SunsetVault<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+/// Anyone can sunset a term vault once it has reached maturity
+pub fn handle_sunset_vault(ctx: Context<SunsetVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    if !vault.sunset(Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::VaultNotMatured.into());
+    }
+
+    emit!(VaultShutDownEvent {
+        vault_key: vault.key,
+        shutdown: true,
+    });
+
+    Ok(())
+}
@@ -10,7 +10,6 @@ use access_control::{
     state::{UserRole, Role}
 };
 
-use strategy::program::Strategy;
 
 use crate::events::UpdatedCurrentDebtForStrategyEvent;
 use crate::state::{StrategyData, Vault};
@@ -68,9 +67,18 @@ pub struct UpdateStrategyDebt<'info> {
 
     pub access_control: Program<'info, AccessControl>,
     pub token_program: Program<'info, Token>,
-    pub strategy_program: Program<'info, Strategy>
+
+    /// CHECK: the program owning the strategy, it has to be the one the strategy was added with
+    #[account(
+        executable,
+        constraint = strategy_program.key() == *strategy.owner,
+        constraint = strategy_program.key() == strategy_data.strategy_program @ ErrorCode::InvalidStrategyProgram,
+    )]
+    pub strategy_program: UncheckedAccount<'info>,
 }
 
+/// Deposits into strategies of programs other than the built-in one need
+/// the vault strategy programs account in the remaining accounts
 pub fn handle_update_debt<'a, 'b, 'c, 'info>(
     mut ctx: Context<'a, 'b, 'c, 'info, UpdateStrategyDebt<'info>>, 
     new_debt: u64,
@@ -107,7 +115,7 @@ fn handle_internal<'a, 'b, 'c, 'info>(
     }
 
     if new_debt < current_debt {
-        let mut assets_to_withdraw = get_assets_to_withdraw(
+        let assets_to_withdraw = get_assets_to_withdraw(
             &vault,
             ctx.accounts.strategy.to_account_info(),
             current_debt,
@@ -116,21 +124,16 @@ fn handle_internal<'a, 'b, 'c, 'info>(
 
         let remaining_accounts: Vec<AccountInfo> = ctx.remaining_accounts.to_vec();
 
-        let withdrawn = strategy_utils::withdraw(
+        strategy_utils::withdraw(
             ctx.accounts.strategy.to_account_info(),
             ctx.accounts.vault.to_account_info(),
             ctx.accounts.strategy_token_account.to_account_info(),
             &mut ctx.accounts.vault_token_account,
             ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.strategy_program.to_account_info(),
             assets_to_withdraw,
             &[&vault_seeds],
             remaining_accounts
         )?;
-    
-        if withdrawn > assets_to_withdraw {
-            assets_to_withdraw = withdrawn;
-        }
 
         new_debt = current_debt - assets_to_withdraw;
 
@@ -140,6 +143,12 @@ fn handle_internal<'a, 'b, 'c, 'info>(
             new_debt
         ));
     } else {
+        // revoked programs keep their funds withdrawable but get no new deposits
+        let strategy_program = ctx.accounts.strategy_program.key();
+        if strategy_program != strategy::ID {
+            strategy_utils::validate_strategy_program(&ctx.accounts.vault.key(), &strategy_program, ctx.remaining_accounts)?;
+        }
+
         let assets_to_deposit = get_assets_deposit(
             &vault,
             ctx.accounts.strategy.to_account_info(),
@@ -152,9 +161,8 @@ fn handle_internal<'a, 'b, 'c, 'info>(
             ctx.accounts.strategy.to_account_info(),
             ctx.accounts.vault.to_account_info(),
             ctx.accounts.strategy_token_account.to_account_info(),
-            ctx.accounts.vault_token_account.to_account_info(),
+            &mut ctx.accounts.vault_token_account,
             ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.strategy_program.to_account_info(),
             assets_to_deposit,
             vault_seeds
         )?;
@@ -224,11 +232,11 @@ fn get_assets_deposit<'info>(
         assets_to_deposit = max_deposit;
     }
 
-    if vault.total_idle <= vault.minimum_total_idle {
+    if vault.available_idle() <= vault.minimum_total_idle {
         return Err(ErrorCode::InsufficientFunds.into());
     }
 
-    let available_idle = vault.total_idle - vault.minimum_total_idle;
+    let available_idle = vault.available_idle() - vault.minimum_total_idle;
     if assets_to_deposit > available_idle {
         assets_to_deposit = available_idle;
     }
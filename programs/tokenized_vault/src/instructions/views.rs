@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Vault;
+
+/// Read-only queries for other programs, results are passed back through return data
+#[derive(Accounts)]
+pub struct VaultView<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+/// Underlying value of `shares`
+pub fn handle_preview_redeem(ctx: Context<VaultView>, shares: u64) -> Result<u64> {
+    Ok(ctx.accounts.vault.load()?.convert_to_underlying(shares))
+}
+
+/// Assets a holder of `shares` can redeem right now from the idle funds,
+/// 0 if the vault does not allow plain redemptions
+pub fn handle_max_withdraw(ctx: Context<VaultView>, shares: u64) -> Result<u64> {
+    let vault = ctx.accounts.vault.load()?;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    if vault.is_paused || vault.is_epoch_based || vault.is_tranched || vault.exit_penalty(0, timestamp).is_err() {
+        return Ok(0);
+    }
+
+    Ok(std::cmp::min(vault.convert_to_underlying(shares), vault.available_idle()))
+}
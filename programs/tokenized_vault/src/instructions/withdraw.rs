@@ -3,9 +3,8 @@ use anchor_spl::{
     token::Token,
     token_interface::{ Mint, TokenAccount },
 };
-use strategy::program::Strategy;
 
-use crate::events::VaultWithdrawlEvent;
+use crate::events::{CircuitBreakerTrippedEvent, VaultShutDownEvent, VaultWithdrawlEvent};
 use crate::state::{StrategyDataAccInfo, Vault};
 use crate::utils::strategy as strategy_utils;
 use crate::utils::token;
@@ -13,6 +12,7 @@ use crate::errors::ErrorCode;
 use crate::constants::{
     UNDERLYING_SEED, 
     SHARES_SEED,
+    STRATEGY_DATA_SEED,
     MAX_BPS
 };
 
@@ -37,7 +37,6 @@ pub struct Withdraw<'info> {
     pub user: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
-    pub strategy_program: Program<'info, Strategy>,
 }
 
 #[derive(Default, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -53,7 +52,38 @@ pub struct AccountsMap {
     pub accounts_map: Vec<AccountsIndexes>,
 }
 
-struct StrategyAccounts<'info> {
+/// Accounts the withdraw flow needs, so wrappers like `withdraw_sol` 
+/// can pay out to an account created in the same instruction
+pub(crate) struct WithdrawAccounts<'a, 'info> {
+    pub vault: &'a AccountLoader<'info, Vault>,
+    pub vault_token_account: &'a mut InterfaceAccount<'info, TokenAccount>,
+    pub shares_mint: AccountInfo<'info>,
+    pub user_shares_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_token_account: AccountInfo<'info>,
+    pub user: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'info> Withdraw<'info> {
+    pub(crate) fn withdraw_accounts<'a>(
+        &'a mut self, 
+        remaining_accounts: &'a [AccountInfo<'info>]
+    ) -> WithdrawAccounts<'a, 'info> {
+        WithdrawAccounts {
+            vault: &self.vault,
+            vault_token_account: &mut self.vault_token_account,
+            shares_mint: self.shares_mint.to_account_info(),
+            user_shares_account: &self.user_shares_account,
+            user_token_account: self.user_token_account.to_account_info(),
+            user: self.user.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            remaining_accounts,
+        }
+    }
+}
+
+pub(crate) struct StrategyAccounts<'info> {
     strategy_acc: AccountInfo<'info>,
     strategy_token_account: AccountInfo<'info>,
     strategy_data: AccountInfo<'info>,
@@ -64,65 +94,148 @@ pub fn handle_withdraw<'info>(
     ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
     assets: u64,
     shares_to_burn: u64,
-    max_loss: u64,
+    max_loss: Option<u64>,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    withdraw(
+        ctx.accounts.withdraw_accounts(remaining_accounts), 
+        assets, 
+        shares_to_burn, 
+        max_loss, 
+        remaining_accounts_map
+    )
+}
+
+/// Redeems the whole share balance and closes the user share account
+pub fn handle_redeem_all<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    max_loss: Option<u64>,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    let shares = ctx.accounts.user_shares_account.amount;
+    let assets = ctx.accounts.vault.load()?.convert_to_underlying(shares);
+
+    let remaining_accounts = ctx.remaining_accounts;
+    withdraw(
+        ctx.accounts.withdraw_accounts(remaining_accounts), 
+        assets, 
+        shares, 
+        max_loss, 
+        remaining_accounts_map
+    )?;
+
+    // nothing is burned when the circuit breaker trips, the account stays open
+    ctx.accounts.user_shares_account.reload()?;
+    if ctx.accounts.user_shares_account.amount > 0 {
+        return Ok(());
+    }
+
+    token::close_account(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_shares_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+    )
+}
+
+pub(crate) fn withdraw<'info>(
+    mut accounts: WithdrawAccounts<'_, 'info>,
+    mut assets: u64,
+    mut shares_to_burn: u64,
+    max_loss: Option<u64>,
     remaining_accounts_map: AccountsMap
 ) -> Result<()> {
     if assets == 0 || shares_to_burn == 0 {
         return Err(ErrorCode::ZeroValue.into());
     }
-    let vault_token_account = &mut ctx.accounts.vault_token_account;
-    let user_shares_balance = ctx.accounts.user_shares_account.amount;
-    let remaining_accounts = ctx.remaining_accounts;
-    let strategies_with_accounts= parse_remaining(remaining_accounts, remaining_accounts_map)?;
+    if accounts.vault.load()?.is_epoch_based {
+        return Err(ErrorCode::EpochBasedVault.into());
+    }
+    if accounts.vault.load()?.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+    let max_loss = accounts.vault.load()?.resolve_max_loss(max_loss)?;
+    let vault_token_account = &mut accounts.vault_token_account;
+    let user_shares_balance = accounts.user_shares_account.amount;
+    let remaining_accounts = accounts.remaining_accounts;
+    let strategies_with_accounts= parse_remaining(&accounts.vault.key(), remaining_accounts, remaining_accounts_map)?;
 
     if user_shares_balance < shares_to_burn {
         return Err(ErrorCode::InsufficientShares.into());
     }
 
+    // positions below the min deposit are not left behind, the user exits fully instead
+    let vault = accounts.vault.load()?;
+    let remaining_shares = user_shares_balance - shares_to_burn;
+    if remaining_shares > 0 && vault.convert_to_underlying(remaining_shares) < vault.min_user_deposit {
+        shares_to_burn = user_shares_balance;
+        assets = vault.convert_to_underlying(user_shares_balance);
+    }
+    drop(vault);
+
     validate_max_withdraw(
-        &ctx.accounts.vault,
+        accounts.vault,
         user_shares_balance, 
         &strategies_with_accounts, 
         max_loss,
         assets
     )?;
 
-    // todo: hadle min user deposit
     let assets_to_transfer = withdraw_assets(
         vault_token_account,
-        &ctx.accounts.token_program.to_account_info(),
-        &ctx.accounts.strategy_program.to_account_info(),
-        &ctx.accounts.vault,
+        &accounts.token_program.to_account_info(),
+        accounts.vault,
         assets,
         &strategies_with_accounts,
     )?;
 
+    // the breaker tripped on a strategy loss, the pause has to be persisted
+    // so the withdrawal succeeds without paying anything out
+    if accounts.vault.load()?.is_paused {
+        return Ok(());
+    }
+
     if assets > assets_to_transfer && max_loss < MAX_BPS {
         if assets - assets_to_transfer > (assets * max_loss) / MAX_BPS {
             return Err(ErrorCode::TooMuchLoss.into());
         }
     }
 
-    ctx.accounts.vault.load_mut()?.handle_withdraw(assets_to_transfer, shares_to_burn);
+    // early exit penalty stays in the vault for the remaining depositors
+    let timestamp = Clock::get()?.unix_timestamp;
+    let penalty = accounts.vault.load()?.exit_penalty(assets_to_transfer, timestamp)?;
+    let assets_to_transfer = assets_to_transfer - penalty;
+
+    let mut vault = accounts.vault.load_mut()?;
+    vault.handle_withdraw(assets_to_transfer, shares_to_burn);
+
+    if vault.sunset(timestamp) {
+        emit!(VaultShutDownEvent {
+            vault_key: vault.key,
+            shutdown: true,
+        });
+    }
+    drop(vault);
 
     token::burn(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.shares_mint.to_account_info(),
-        ctx.accounts.user_shares_account.to_account_info(),
-        ctx.accounts.user.to_account_info(),
+        accounts.token_program.to_account_info(),
+        accounts.shares_mint.to_account_info(),
+        accounts.user_shares_account.to_account_info(),
+        accounts.user.to_account_info(),
         shares_to_burn
     )?;
 
     token::transfer_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.vault_token_account.to_account_info(),
-        ctx.accounts.user_token_account.to_account_info(),
-        ctx.accounts.vault.to_account_info(),
+        accounts.token_program.to_account_info(),
+        accounts.vault_token_account.to_account_info(),
+        accounts.user_token_account.to_account_info(),
+        accounts.vault.to_account_info(),
         assets_to_transfer,
-        &ctx.accounts.vault.load()?.seeds()
+        &accounts.vault.load()?.seeds()
     )?;
 
-    let vault = ctx.accounts.vault.load()?;
+    let vault = accounts.vault.load()?;
 
     emit!(VaultWithdrawlEvent {
         vault_key: vault.key,
@@ -130,17 +243,20 @@ pub fn handle_withdraw<'info>(
         total_share: vault.total_shares(),
         assets_to_transfer,
         shares_to_burn,
-        token_account: ctx.accounts.user_token_account.to_account_info().key(),
-        share_account: ctx.accounts.user_shares_account.to_account_info().key(),
-        token_mint: ctx.accounts.vault_token_account.mint,
-        share_mint: ctx.accounts.shares_mint.to_account_info().key(),
-        authority: ctx.accounts.user.to_account_info().key(),
+        token_account: accounts.user_token_account.to_account_info().key(),
+        share_account: accounts.user_shares_account.to_account_info().key(),
+        token_mint: accounts.vault_token_account.mint,
+        share_mint: accounts.shares_mint.to_account_info().key(),
+        authority: accounts.user.to_account_info().key(),
     });
 
     Ok(())
 }
 
-fn parse_remaining<'info>(
+/// Strategy data accounts must be the vault's PDAs for the given strategies,
+/// and the strategies must still be owned by the program they were added with
+pub(crate) fn parse_remaining<'info>(
+    vault: &Pubkey,
     remaining_accounts: &[AccountInfo<'info>], 
     remaining_accounts_map: AccountsMap
 ) -> Result<Box<Vec<StrategyAccounts<'info>>>> {
@@ -152,6 +268,15 @@ fn parse_remaining<'info>(
         let strategy_token_account = &remaining_accounts[accounts_map[i].strategy_token_account as usize];
         let strategy_data = &remaining_accounts[accounts_map[i].strategy_data as usize];
 
+        let expected_strategy_data = Pubkey::find_program_address(
+            &[STRATEGY_DATA_SEED.as_bytes(), vault.as_ref(), strategy_acc.key().as_ref()],
+            &crate::ID,
+        ).0;
+        if strategy_data.key() != expected_strategy_data || *strategy_data.owner != crate::ID {
+            return Err(ErrorCode::InvalidStrategyData.into());
+        }
+        strategy_data.deserialize()?.check_strategy_program(strategy_acc)?;
+
         let mut strategy_remaining_accounts: Vec<AccountInfo<'info>> = Vec::new();
         if !accounts_map[i].remaining_accounts.is_empty() && accounts_map[i].remaining_accounts.len() > 0 {
             for remaining_i in accounts_map[i].remaining_accounts.iter() {
@@ -181,8 +306,8 @@ fn validate_max_withdraw<'info>(
     let vault = vault_acc.load()?;
     let mut max_assets = vault.convert_to_underlying(shares);
 
-    if max_assets > vault.total_idle {
-        let mut have = vault.total_idle;
+    if max_assets > vault.available_idle() {
+        let mut have = vault.available_idle();
         let mut loss = 0;
 
         for strategy_accounts in strategies {
@@ -229,10 +354,34 @@ fn validate_max_withdraw<'info>(
     Ok(())
 }
 
-fn withdraw_assets<'info>(
+pub(crate) fn withdraw_assets<'info>(
+    vault_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    token_program: &AccountInfo<'info>,
+    vault_acc: &AccountLoader<'info, Vault>,
+    assets: u64,
+    strategies: &Vec<StrategyAccounts<'info>>, 
+) -> Result<u64> {
+    let requested_assets = pull_from_strategies(
+        vault_token_account,
+        token_program,
+        vault_acc,
+        assets,
+        strategies,
+    )?;
+
+    if vault_acc.load()?.available_idle() < requested_assets {
+        return Err(ErrorCode::InsufficientFunds.into());
+    }
+
+    Ok(requested_assets)
+}
+
+/// Moves funds from the given strategies to the vault idle until `assets` are covered
+/// or the strategies run out. Returns `assets` minus the losses the withdrawer takes,
+/// 0 if the circuit breaker tripped.
+pub(crate) fn pull_from_strategies<'info>(
     vault_token_account: &mut InterfaceAccount<'info, TokenAccount>,
     token_program: &AccountInfo<'info>,
-    strategy_program: &AccountInfo<'info>,
     vault_acc: &AccountLoader<'info, Vault>,
     assets: u64,
     strategies: &Vec<StrategyAccounts<'info>>, 
@@ -241,9 +390,11 @@ fn withdraw_assets<'info>(
     let mut requested_assets = assets;
     let mut total_idle = vault.total_idle;
     let mut total_debt = vault.total_debt;
+    // idle reserved for withdrawal sessions can't cover this withdrawal
+    let reserved_idle = vault.reserved_idle;
 
-    if requested_assets > total_idle {
-        let mut assets_needed = requested_assets - total_idle;
+    if requested_assets + reserved_idle > total_idle {
+        let mut assets_needed = requested_assets + reserved_idle - total_idle;
 
         for i in 0..strategies.len() {
             let strategy_acc = &strategies[i].strategy_acc;
@@ -258,6 +409,10 @@ fn withdraw_assets<'info>(
             )?;
 
             if unrealised_loss_share > 0 {
+                if check_strategy_loss(vault_acc, strategy_acc, current_debt, total_idle + total_debt)? {
+                    return Ok(0);
+                }
+
                 if strategy_limit < to_withdraw - unrealised_loss_share {
                     let wanted = to_withdraw - unrealised_loss_share;
                     unrealised_loss_share = (unrealised_loss_share * strategy_limit) / wanted;
@@ -281,54 +436,63 @@ fn withdraw_assets<'info>(
                 continue;
             }
 
-            let withdrawn = strategy_utils::withdraw(
+            strategy_utils::withdraw(
                 strategy_acc.to_account_info(),
                 vault_acc.to_account_info(),
                 strategies[i].strategy_token_account.to_account_info(),
                 vault_token_account,
                 token_program.to_account_info(),
-                strategy_program.to_account_info(),
                 to_withdraw,
                 &[&vault.seeds()],
                 strategies[i].remaining_accounts.clone()
             )?;
 
-            let mut loss = 0;
-
-            if withdrawn > to_withdraw {
-                if withdrawn > current_debt {
-                    to_withdraw = current_debt;
-                } else {
-                    to_withdraw = withdrawn;
-                }
-            } else if withdrawn < to_withdraw {
-                loss = to_withdraw - withdrawn;
-            }
-
-            total_idle += to_withdraw - loss;
-            requested_assets -= loss;
+            total_idle += to_withdraw;
             total_debt -= to_withdraw;
 
             let new_debt: u64 = current_debt - (to_withdraw + unrealised_loss_share);
 
-            let vault_mut = &mut vault_acc.load_mut()?;
+            let mut vault_mut = vault_acc.load_mut()?;
 
             strategies[i].strategy_data.set_current_debt(new_debt)?;
 
             vault_mut.total_debt = total_debt;
             vault_mut.total_idle = total_idle;
+            drop(vault_mut);
 
-            if requested_assets <= total_idle {
+            if requested_assets + reserved_idle <= total_idle {
                 break;
             }
 
             assets_needed -= to_withdraw;
         }
-
-        if total_idle < requested_assets {
-            return Err(ErrorCode::InsufficientFunds.into());
-        }
     }
 
     Ok(requested_assets)
 }
+
+/// Marks the strategy at its real value and trips the vault circuit breaker 
+/// if the resulting price per share is too far below the last reported one
+fn check_strategy_loss<'info>(
+    vault_acc: &AccountLoader<'info, Vault>,
+    strategy_acc: &AccountInfo<'info>,
+    current_debt: u64,
+    total_funds: u64,
+) -> Result<bool> {
+    let strategy_assets = strategy_utils::get_total_assets(strategy_acc)?;
+    let strategy_loss = current_debt.saturating_sub(strategy_assets);
+
+    let mut vault = vault_acc.load_mut()?;
+    if !vault.check_circuit_breaker(total_funds.saturating_sub(strategy_loss)) {
+        return Ok(false);
+    }
+
+    emit!(CircuitBreakerTrippedEvent {
+        vault_key: vault.key,
+        last_price_per_share: vault.last_price_per_share,
+        price_per_share: vault.price_per_share_at(total_funds.saturating_sub(strategy_loss)),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(true)
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::{INSURANCE_SEED, SHARES_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::events::InsuranceWithdrawnEvent;
+use crate::state::Vault;
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct WithdrawInsurance<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(mut)]
+    pub recipient: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Account<'info, Mint>,
+
+    #[account(mut, seeds = [INSURANCE_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub insurance_shares_account: Account<'info, TokenAccount>,
+
+    #[account(mut, address = vault.load()?.insurance_owner @ErrorCode::AccessDenied)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// The reserve owner can only take out what is above the target size,
+/// and only from the idle funds of the vault
+pub fn handle_withdraw_insurance(ctx: Context<WithdrawInsurance>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+
+    let vault = ctx.accounts.vault.load()?;
+    let reserve = vault.convert_to_underlying(ctx.accounts.insurance_shares_account.amount);
+
+    if reserve <= vault.insurance_target || amount > reserve - vault.insurance_target {
+        return Err(ErrorCode::InsuranceBelowTarget.into());
+    }
+
+    if amount > vault.available_idle() {
+        return Err(ErrorCode::InsufficientFunds.into());
+    }
+
+    let shares = vault.convert_to_shares(amount);
+    drop(vault);
+
+    token::burn_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        ctx.accounts.insurance_shares_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        shares,
+        &ctx.accounts.vault.load()?.seeds(),
+    )?;
+
+    token::transfer_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        amount,
+        &ctx.accounts.vault.load()?.seeds(),
+    )?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.handle_withdraw(amount, shares);
+
+    emit!(InsuranceWithdrawnEvent {
+        vault_key: vault.key,
+        amount,
+        shares,
+        recipient: ctx.accounts.recipient.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{spl_token::native_mint, Token},
+    token_interface::{ Mint, TokenAccount },
+};
+
+use crate::constants::{SHARES_SEED, TEMP_WSOL_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::withdraw::{withdraw, AccountsMap, WithdrawAccounts};
+use crate::state::Vault;
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    #[account(
+        mut, 
+        constraint = vault.load()?.underlying_mint == native_mint.key() @ErrorCode::NotNativeMintVault
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init,
+        seeds = [TEMP_WSOL_SEED.as_bytes(), user.key().as_ref()],
+        bump,
+        payer = user,
+        token::mint = native_mint,
+        token::authority = user,
+    )]
+    pub temp_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = native_mint::ID)]
+    pub native_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraws into a temporary wSOL account and closes it, 
+/// so the user gets back lamports
+pub fn handle_withdraw_sol<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawSol<'info>>,
+    amount: u64,
+    max_loss: Option<u64>,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    let shares = ctx.accounts.vault.load()?.convert_to_shares(amount);
+    let accounts = ctx.accounts;
+
+    withdraw(
+        WithdrawAccounts {
+            vault: &accounts.vault,
+            vault_token_account: &mut accounts.vault_token_account,
+            shares_mint: accounts.shares_mint.to_account_info(),
+            user_shares_account: &accounts.user_shares_account,
+            user_token_account: accounts.temp_wsol_account.to_account_info(),
+            user: accounts.user.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        amount,
+        shares,
+        max_loss,
+        remaining_accounts_map
+    )?;
+
+    token::close_account(
+        accounts.token_program.to_account_info(),
+        accounts.temp_wsol_account.to_account_info(),
+        accounts.user.to_account_info(),
+        accounts.user.to_account_info(),
+    )
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{ Mint, TokenAccount },
+};
+
+use crate::constants::{
+    MAX_BPS,
+    SHARES_SEED,
+    TRANCHES_POOL_SEED,
+    TRANCHES_SEED,
+    TRANCHE_SHARES_SEED,
+    UNDERLYING_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::TrancheWithdrawEvent;
+use crate::instructions::withdraw::{parse_remaining, withdraw_assets, AccountsMap};
+use crate::state::{Tranche, Tranches, Vault};
+use crate::utils::token;
+
+#[derive(Accounts)]
+#[instruction(tranche: Tranche)]
+pub struct WithdrawTranche<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(mut, seeds = [TRANCHES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub tranches: Box<Account<'info, Tranches>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [TRANCHES_POOL_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub pool_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TRANCHE_SHARES_SEED.as_bytes(),
+            vault.key().as_ref(),
+            tranche.to_seed().as_ref()
+        ],
+        bump
+    )]
+    pub tranche_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_tranche_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_withdraw_tranche<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawTranche<'info>>,
+    tranche: Tranche,
+    shares: u64,
+    max_loss: Option<u64>,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    if shares == 0 {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+
+    if ctx.accounts.vault.load()?.is_paused {
+        return Err(ErrorCode::VaultPaused.into());
+    }
+    let max_loss = ctx.accounts.vault.load()?.resolve_max_loss(max_loss)?;
+
+    if ctx.accounts.user_tranche_account.amount < shares {
+        return Err(ErrorCode::InsufficientShares.into());
+    }
+
+    ctx.accounts.tranches.accrue()?;
+    let assets = ctx.accounts.tranches.convert_to_underlying(tranche, shares, ctx.accounts.tranche_mint.supply);
+
+    if assets == 0 {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+
+    let vault_shares = std::cmp::min(
+        ctx.accounts.vault.load()?.convert_to_shares(assets),
+        ctx.accounts.tranches.pool_shares
+    );
+
+    let strategies_with_accounts = parse_remaining(&ctx.accounts.vault.key(), ctx.remaining_accounts, remaining_accounts_map)?;
+
+    let assets_to_transfer = withdraw_assets(
+        &mut ctx.accounts.vault_token_account,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.vault,
+        assets,
+        &strategies_with_accounts,
+    )?;
+
+    if ctx.accounts.vault.load()?.is_paused {
+        return Ok(());
+    }
+
+    if assets > assets_to_transfer
+        && max_loss < MAX_BPS
+        && assets - assets_to_transfer > (assets * max_loss) / MAX_BPS
+    {
+        return Err(ErrorCode::TooMuchLoss.into());
+    }
+
+    let tranches = &mut ctx.accounts.tranches;
+    tranches.handle_withdraw(tranche, assets, vault_shares);
+
+    // junior withdrawals shrink the senior cushion
+    if tranche == Tranche::Junior {
+        tranches.check_subordination()?;
+    }
+
+    let penalty = ctx.accounts.vault.load()?.exit_penalty(assets_to_transfer, Clock::get()?.unix_timestamp)?;
+    let assets_to_transfer = assets_to_transfer - penalty;
+
+    ctx.accounts.vault.load_mut()?.handle_withdraw(assets_to_transfer, vault_shares);
+
+    token::burn(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.tranche_mint.to_account_info(),
+        ctx.accounts.user_tranche_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        shares
+    )?;
+
+    token::burn_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_mint.to_account_info(),
+        ctx.accounts.pool_shares_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        vault_shares,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    token::transfer_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        assets_to_transfer,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    emit!(TrancheWithdrawEvent {
+        vault_key: ctx.accounts.vault.key(),
+        tranche: tranche as u8,
+        assets_to_transfer,
+        shares_to_burn: shares,
+        vault_shares,
+        authority: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
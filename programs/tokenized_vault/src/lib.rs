@@ -7,7 +7,7 @@ pub mod events;
 
 use anchor_lang::prelude::*;
 
-pub use state::{SharesConfig, VaultConfig};
+pub use state::{InsuranceConfig, SharesConfig, Tranche, VaultConfig};
 pub use instructions::*;
 
 declare_id!("8eDcyX8Z8yZXBQsuatwxDC1qzGbuUbP7wGERDBQoPmBH");
@@ -32,10 +32,14 @@ pub mod tokenized_vault {
         handle_deposit(ctx, amount)
     }
 
+    pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+        handle_deposit_sol(ctx, amount)
+    }
+
     pub fn withdraw<'info>(
         ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>, 
         amount: u64, 
-        max_loss: u64,
+        max_loss: Option<u64>,
         remaining_accounts_map: AccountsMap
     ) -> Result<()> {
         let shares = ctx.accounts.vault.load()?.convert_to_shares(amount);
@@ -45,17 +49,112 @@ pub mod tokenized_vault {
     pub fn redeem<'info>(
         ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>, 
         shares: u64, 
-        max_loss: u64,
+        max_loss: Option<u64>,
         remaining_accounts_map: AccountsMap
     ) -> Result<()> {
         let amount = ctx.accounts.vault.load()?.convert_to_underlying(shares);
         handle_withdraw(ctx, amount, shares, max_loss, remaining_accounts_map)
     }
 
+    pub fn redeem_all<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>, 
+        max_loss: Option<u64>,
+        remaining_accounts_map: AccountsMap
+    ) -> Result<()> {
+        handle_redeem_all(ctx, max_loss, remaining_accounts_map)
+    }
+
+    pub fn withdraw_sol<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawSol<'info>>, 
+        amount: u64, 
+        max_loss: Option<u64>,
+        remaining_accounts_map: AccountsMap
+    ) -> Result<()> {
+        handle_withdraw_sol(ctx, amount, max_loss, remaining_accounts_map)
+    }
+
+    pub fn start_withdraw(ctx: Context<StartWithdraw>, shares: u64, max_loss: Option<u64>) -> Result<()> {
+        handle_start_withdraw(ctx, shares, max_loss)
+    }
+
+    pub fn continue_withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, ContinueWithdraw<'info>>, 
+        remaining_accounts_map: AccountsMap
+    ) -> Result<()> {
+        handle_continue_withdraw(ctx, remaining_accounts_map)
+    }
+
+    pub fn finalize_withdraw(ctx: Context<FinalizeWithdraw>) -> Result<()> {
+        handle_finalize_withdraw(ctx)
+    }
+
+    pub fn cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+        handle_cancel_withdraw(ctx)
+    }
+
+    pub fn migrate_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigratePosition<'info>>, 
+        shares: u64, 
+        max_loss: Option<u64>,
+        remaining_accounts_map: AccountsMap
+    ) -> Result<()> {
+        handle_migrate_position(ctx, shares, max_loss, remaining_accounts_map)
+    }
+
+    pub fn init_tranches(
+        ctx: Context<InitTranches>, 
+        senior_target_yield: u64, 
+        min_subordination: u64
+    ) -> Result<()> {
+        handle_init_tranches(ctx, senior_target_yield, min_subordination)
+    }
+
+    pub fn deposit_tranche(ctx: Context<DepositTranche>, tranche: Tranche, amount: u64) -> Result<()> {
+        handle_deposit_tranche(ctx, tranche, amount)
+    }
+
+    pub fn withdraw_tranche<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawTranche<'info>>, 
+        tranche: Tranche,
+        shares: u64, 
+        max_loss: Option<u64>,
+        remaining_accounts_map: AccountsMap
+    ) -> Result<()> {
+        handle_withdraw_tranche(ctx, tranche, shares, max_loss, remaining_accounts_map)
+    }
+
+    pub fn init_epochs(ctx: Context<InitEpochs>) -> Result<()> {
+        handle_init_epochs(ctx)
+    }
+
+    pub fn request_deposit(ctx: Context<RequestDeposit>, amount: u64) -> Result<()> {
+        handle_request_deposit(ctx, amount)
+    }
+
+    pub fn request_redeem(ctx: Context<RequestRedeem>, shares: u64) -> Result<()> {
+        handle_request_redeem(ctx, shares)
+    }
+
+    pub fn close_epoch(ctx: Context<CloseEpoch>) -> Result<()> {
+        handle_close_epoch(ctx)
+    }
+
+    pub fn claim_epoch(ctx: Context<ClaimEpoch>) -> Result<()> {
+        handle_claim_epoch(ctx)
+    }
+
     pub fn add_strategy(ctx: Context<AddStrategy>, max_debt: u64) -> Result<()> {
         handle_add_strategy(ctx, max_debt)
     }
 
+    pub fn approve_strategy_program(ctx: Context<ApproveStrategyProgram>) -> Result<()> {
+        handle_approve_strategy_program(ctx)
+    }
+
+    pub fn revoke_strategy_program(ctx: Context<RevokeStrategyProgram>, program: Pubkey) -> Result<()> {
+        handle_revoke_strategy_program(ctx, program)
+    }
+
     pub fn remove_strategy(ctx: Context<RemoveStrategy>, strategy: Pubkey, force: bool) -> Result<()> {
         handle_remove_strategy(ctx, strategy, force)
     }
@@ -71,6 +170,42 @@ pub mod tokenized_vault {
         handle_set_deposit_limit(ctx, limit)
     }
 
+    pub fn set_default_max_loss(ctx: Context<SetDefaultMaxLoss>, max_loss: u64) -> Result<()> {
+        handle_set_default_max_loss(ctx, max_loss)
+    }
+
+    pub fn set_insurance_config(ctx: Context<SetInsuranceConfig>, config: InsuranceConfig) -> Result<()> {
+        handle_set_insurance_config(ctx, config)
+    }
+
+    pub fn fund_insurance(ctx: Context<FundInsurance>, amount: u64) -> Result<()> {
+        handle_fund_insurance(ctx, amount)
+    }
+
+    pub fn withdraw_insurance(ctx: Context<WithdrawInsurance>, amount: u64) -> Result<()> {
+        handle_withdraw_insurance(ctx, amount)
+    }
+
+    pub fn set_circuit_breaker(ctx: Context<SetCircuitBreaker>, pps_drop_threshold: u64) -> Result<()> {
+        handle_set_circuit_breaker(ctx, pps_drop_threshold)
+    }
+
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        handle_reset_circuit_breaker(ctx)
+    }
+
+    pub fn set_strategy_health_check(
+        ctx: Context<SetStrategyHealthCheck>, 
+        max_profit_bps: u64, 
+        max_loss_bps: u64
+    ) -> Result<()> {
+        handle_set_strategy_health_check(ctx, max_profit_bps, max_loss_bps)
+    }
+
+    pub fn override_health_check(ctx: Context<OverrideHealthCheck>) -> Result<()> {
+        handle_override_health_check(ctx)
+    }
+
     pub fn process_report(ctx: Context<ProcessReport>) -> Result<()> {
         handle_process_report(ctx)
     }
@@ -79,7 +214,31 @@ pub mod tokenized_vault {
         handle_shutdown_vault(ctx)
     }
 
+    pub fn sunset_vault(ctx: Context<SunsetVault>) -> Result<()> {
+        handle_sunset_vault(ctx)
+    }
+
+    pub fn migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+        handle_migrate_vault(ctx)
+    }
+
+    pub fn migrate_strategy_data(ctx: Context<MigrateStrategyData>) -> Result<()> {
+        handle_migrate_strategy_data(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        handle_migrate_config(ctx)
+    }
+
     pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
         handle_close_vault(ctx)
     }
+
+    pub fn preview_redeem(ctx: Context<VaultView>, shares: u64) -> Result<u64> {
+        handle_preview_redeem(ctx, shares)
+    }
+
+    pub fn max_withdraw(ctx: Context<VaultView>, shares: u64) -> Result<u64> {
+        handle_max_withdraw(ctx, shares)
+    }
 }
\ No newline at end of file
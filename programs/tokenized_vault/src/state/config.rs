@@ -1,8 +1,18 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::DISCRIMINATOR_LEN;
+
 #[account]
 #[derive(Default, Debug, InitSpace)]
 pub struct Config {
     pub next_vault_index: u64,
+
+    // layout version, new fields are carved out of the reserved space
+    pub version: u8,
+    pub reserved: [u64; 4],
+}
+
+impl Config {
+    pub const LEN: usize = DISCRIMINATOR_LEN + Config::INIT_SPACE;
 }
 
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DISCRIMINATOR_LEN;
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct Epoch {
+    pub vault: Pubkey,
+    pub index: u64,
+    pub is_settled: bool,
+
+    // queued underlying and shares
+    pub total_deposits: u64,
+    pub total_redemptions: u64,
+
+    // what the queued requests got at settlement
+    pub deposit_shares: u64,
+    pub redeem_assets: u64,
+    pub settled_at: i64,
+}
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct EpochRequest {
+    pub epoch: Pubkey,
+    pub user: Pubkey,
+    pub deposit_amount: u64,
+    pub redeem_shares: u64,
+}
+
+impl Epoch {
+    pub const LEN: usize = DISCRIMINATOR_LEN + Epoch::INIT_SPACE;
+
+    pub fn init(&mut self, vault: Pubkey, index: u64) {
+        self.vault = vault;
+        self.index = index;
+    }
+
+    pub fn settle(&mut self, deposit_shares: u64, redeem_assets: u64) -> Result<()> {
+        self.deposit_shares = deposit_shares;
+        self.redeem_assets = redeem_assets;
+        self.is_settled = true;
+        self.settled_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Shares owed to a request, every request of the epoch gets the same price
+    pub fn shares_for(&self, request: &EpochRequest) -> u64 {
+        if self.total_deposits == 0 {
+            return 0;
+        }
+        (request.deposit_amount as u128 * self.deposit_shares as u128 / self.total_deposits as u128) as u64
+    }
+
+    pub fn assets_for(&self, request: &EpochRequest) -> u64 {
+        if self.total_redemptions == 0 {
+            return 0;
+        }
+        (request.redeem_shares as u128 * self.redeem_assets as u128 / self.total_redemptions as u128) as u64
+    }
+}
+
+impl EpochRequest {
+    pub const LEN: usize = DISCRIMINATOR_LEN + EpochRequest::INIT_SPACE;
+}
@@ -1,7 +1,15 @@
 pub mod config;
+pub mod epoch;
 pub mod vault;
 pub mod strategy_data;
+pub mod strategy_programs;
+pub mod tranches;
+pub mod withdrawal_session;
 
 pub use config::*;
+pub use epoch::*;
 pub use vault::*;
 pub use strategy_data::*;
+pub use strategy_programs::*;
+pub use tranches::*;
+pub use withdrawal_session::*;
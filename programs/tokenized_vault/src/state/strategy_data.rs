@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::DISCRIMINATOR_LEN;
+use crate::constants::{DISCRIMINATOR_LEN, MAX_BPS, STRATEGY_DATA_VERSION};
+use crate::errors::ErrorCode;
 use crate::events::VaultAddStrategyEvent;
 
 
@@ -11,6 +12,18 @@ pub struct StrategyData {
     pub current_debt: u64,
     pub max_debt: u64,
     pub last_update: i64,
+
+    // health check limits relative to current debt, 0 means no limit
+    pub max_profit_bps: u64,
+    pub max_loss_bps: u64,
+    // one-time override of the health check for the next report
+    pub skip_health_check: bool,
+
+    // layout version, new fields are carved out of the reserved space
+    pub version: u8,
+    // program the strategy account was owned by when it was added
+    pub strategy_program: Pubkey,
+    pub reserved: [u64; 4],
 }
 
 pub fn deserialize(acc_info: &AccountInfo) -> Result<Box<StrategyData>> {
@@ -48,8 +61,10 @@ impl<'a> StrategyDataAccInfo for AccountInfo<'a> {
 impl StrategyData {
     pub const LEN: usize = DISCRIMINATOR_LEN + StrategyData::INIT_SPACE;
 
-    pub fn init(&mut self, strategy: Pubkey, max_debt: u64) -> Result<()> {
+    pub fn init(&mut self, strategy: Pubkey, strategy_program: Pubkey, max_debt: u64) -> Result<()> {
+        self.version = STRATEGY_DATA_VERSION;
         self.key = strategy;
+        self.strategy_program = strategy_program;
         self.max_debt = max_debt;
         self.last_update = 0;
 
@@ -65,9 +80,38 @@ impl StrategyData {
         Ok(())
     }
 
+    /// Fails if the strategy is no longer owned by the program it was added with
+    pub fn check_strategy_program(&self, strategy: &AccountInfo) -> Result<()> {
+        if *strategy.owner != self.strategy_program {
+            return Err(ErrorCode::InvalidStrategyProgram.into());
+        }
+        Ok(())
+    }
+
+    /// Consumes the one-time health check override, returns true if it was set
+    pub fn take_health_check_override(&mut self) -> bool {
+        std::mem::take(&mut self.skip_health_check)
+    }
+
+    pub fn check_health(&self, profit: u64, loss: u64) -> Result<()> {
+        if self.max_profit_bps > 0 && profit > bps_of(self.current_debt, self.max_profit_bps) {
+            return Err(ErrorCode::ProfitLimitExceeded.into());
+        }
+
+        if self.max_loss_bps > 0 && loss > bps_of(self.current_debt, self.max_loss_bps) {
+            return Err(ErrorCode::LossLimitExceeded.into());
+        }
+
+        Ok(())
+    }
+
     pub fn update_strategy_current_debt(&mut self,  amount: u64) -> Result<()> {
         self.current_debt = amount;
         self.last_update = Clock::get()?.unix_timestamp;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+fn bps_of(amount: u64, bps: u64) -> u64 {
+    (amount as u128 * bps as u128 / MAX_BPS as u128) as u64
+}
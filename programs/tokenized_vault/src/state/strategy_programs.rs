@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISCRIMINATOR_LEN, MAX_STRATEGY_PROGRAMS};
+use crate::errors::ErrorCode;
+
+/// Programs besides the built-in strategy program
+/// whose strategies can be added to the vault
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct StrategyPrograms {
+    pub vault: Pubkey,
+    #[max_len(MAX_STRATEGY_PROGRAMS)]
+    pub programs: Vec<Pubkey>,
+}
+
+impl StrategyPrograms {
+    pub const LEN: usize = DISCRIMINATOR_LEN + StrategyPrograms::INIT_SPACE;
+
+    pub fn is_approved(&self, program: &Pubkey) -> bool {
+        self.programs.contains(program)
+    }
+
+    pub fn approve(&mut self, program: Pubkey) -> Result<()> {
+        if self.is_approved(&program) {
+            return Ok(());
+        }
+
+        if self.programs.len() >= MAX_STRATEGY_PROGRAMS {
+            return Err(ErrorCode::StrategyProgramsFull.into());
+        }
+
+        self.programs.push(program);
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, program: &Pubkey) -> Result<()> {
+        let index = self.programs
+            .iter()
+            .position(|p| p == program)
+            .ok_or(ErrorCode::StrategyProgramNotApproved)?;
+
+        self.programs.remove(index);
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISCRIMINATOR_LEN, MAX_BPS, SECS_PER_YEAR};
+use crate::errors::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Tranche {
+    Senior,
+    Junior,
+}
+
+impl Tranche {
+    pub fn to_seed(self) -> [u8; 1] {
+        [self as u8]
+    }
+}
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct Tranches {
+    pub vault: Pubkey,
+    pub senior_mint: Pubkey,
+    pub junior_mint: Pubkey,
+
+    // vault shares held in the tranches pool
+    pub pool_shares: u64,
+
+    // split of the pool assets, updated on each report
+    pub senior_assets: u64,
+    pub junior_assets: u64,
+
+    // annual yield targeted by the senior tranche, in bps
+    pub senior_target_yield: u64,
+    // min share of the junior tranche in the pool assets, in bps
+    pub min_subordination: u64,
+
+    pub last_update: i64,
+}
+
+impl Tranches {
+    pub const LEN: usize = DISCRIMINATOR_LEN + Tranches::INIT_SPACE;
+
+    pub fn init(
+        &mut self,
+        vault: Pubkey,
+        senior_mint: Pubkey,
+        junior_mint: Pubkey,
+        senior_target_yield: u64,
+        min_subordination: u64,
+    ) -> Result<()> {
+        if min_subordination > MAX_BPS {
+            return Err(ErrorCode::InvalidTranchesConfig.into());
+        }
+
+        self.vault = vault;
+        self.senior_mint = senior_mint;
+        self.junior_mint = junior_mint;
+        self.senior_target_yield = senior_target_yield;
+        self.min_subordination = min_subordination;
+        self.last_update = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    pub fn mint(&self, tranche: Tranche) -> Pubkey {
+        match tranche {
+            Tranche::Senior => self.senior_mint,
+            Tranche::Junior => self.junior_mint,
+        }
+    }
+
+    pub fn assets(&self, tranche: Tranche) -> u64 {
+        match tranche {
+            Tranche::Senior => self.senior_assets,
+            Tranche::Junior => self.junior_assets,
+        }
+    }
+
+    pub fn total_assets(&self) -> u64 {
+        self.senior_assets + self.junior_assets
+    }
+
+    pub fn convert_to_shares(&self, tranche: Tranche, amount: u64, supply: u64) -> Result<u64> {
+        if supply == 0 {
+            return Ok(amount);
+        }
+
+        // shares of a wiped out tranche are worth nothing, pricing new ones at 1:1 would hand the deposit to them
+        let assets = self.assets(tranche);
+        if assets == 0 {
+            return Err(ErrorCode::TrancheWipedOut.into());
+        }
+
+        Ok((amount as u128 * supply as u128 / assets as u128) as u64)
+    }
+
+    pub fn convert_to_underlying(&self, tranche: Tranche, shares: u64, supply: u64) -> u64 {
+        if supply == 0 {
+            0
+        } else {
+            (shares as u128 * self.assets(tranche) as u128 / supply as u128) as u64
+        }
+    }
+
+    pub fn handle_deposit(&mut self, tranche: Tranche, amount: u64, vault_shares: u64) {
+        match tranche {
+            Tranche::Senior => self.senior_assets += amount,
+            Tranche::Junior => self.junior_assets += amount,
+        }
+        self.pool_shares += vault_shares;
+    }
+
+    pub fn handle_withdraw(&mut self, tranche: Tranche, amount: u64, vault_shares: u64) {
+        match tranche {
+            Tranche::Senior => self.senior_assets -= amount,
+            Tranche::Junior => self.junior_assets -= amount,
+        }
+        self.pool_shares -= vault_shares;
+    }
+
+    pub fn check_subordination(&self) -> Result<()> {
+        if self.senior_assets == 0 {
+            return Ok(());
+        }
+
+        let junior_bps = self.junior_assets as u128 * MAX_BPS as u128 / self.total_assets() as u128;
+        if junior_bps < self.min_subordination as u128 {
+            return Err(ErrorCode::SubordinationTooLow.into());
+        }
+
+        Ok(())
+    }
+
+    /// Splits the pool assets between tranches. The senior tranche is paid its
+    /// target yield first and the junior tranche gets whatever is left,
+    /// so losses hit the junior tranche until it is wiped out.
+    pub fn update_split(&mut self, total_assets: u64) -> Result<()> {
+        let curr_timestamp = Clock::get()?.unix_timestamp;
+        let duration = curr_timestamp.saturating_sub(self.last_update) as u128;
+
+        let accrued = self.senior_assets as u128 * self.senior_target_yield as u128 * duration
+            / (MAX_BPS as u128 * SECS_PER_YEAR as u128);
+        let senior_target = self.senior_assets as u128 + accrued;
+
+        self.senior_assets = std::cmp::min(senior_target, total_assets as u128) as u64;
+        self.junior_assets = total_assets - self.senior_assets;
+        self.last_update = curr_timestamp;
+
+        Ok(())
+    }
+
+    /// Moves the senior yield accrued since the last update out of the junior tranche,
+    /// the pool value itself is only updated on reports
+    pub fn accrue(&mut self) -> Result<()> {
+        self.update_split(self.total_assets())
+    }
+}
@@ -1,7 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
-use crate::constants::{DISCRIMINATOR_LEN, VAULT_SEED, SHARES_SEED, MAX_BPS_EXTENDED};
+use crate::constants::{
+    DISCRIMINATOR_LEN,
+    VAULT_SEED,
+    SHARES_SEED,
+    SHARES_DECIMALS,
+    MAX_BPS,
+    MAX_BPS_EXTENDED,
+    VAULT_VERSION,
+};
+use crate::errors::ErrorCode;
 
 #[account(zero_copy(unsafe))]
 #[repr(packed)]
@@ -35,6 +44,40 @@ pub struct Vault {
     pub full_profit_unlock_date: u64,
     pub profit_unlocking_rate: u64,
     pub last_profit_update: u64,
+
+    // insurance reserve, kept as vault shares in the insurance PDA
+    pub insurance_fee: u64,
+    pub insurance_target: u64,
+    pub insurance_owner: Pubkey,
+
+    // shares are held by the senior/junior tranches instead of users
+    pub is_tranched: bool,
+
+    // deposits and redemptions are queued and settled per epoch
+    pub is_epoch_based: bool,
+    pub current_epoch: u64,
+
+    // term vaults, 0 means no window / no maturity
+    pub deposit_window_end: i64,
+    pub maturity: i64,
+    // penalty for redeeming before maturity in bps, 0 means no early exit
+    pub early_exit_penalty: u64,
+
+    // circuit breaker, trips when the price per share drops by more than
+    // the threshold (in bps) from the last reported one, 0 means disabled
+    pub last_price_per_share: u64,
+    pub pps_drop_threshold: u64,
+    pub is_paused: bool,
+
+    // max_loss used by withdrawals that don't pass one, in bps
+    pub default_max_loss: u64,
+
+    // layout version, new fields are carved out of the reserved space
+    pub version: u8,
+
+    // idle pulled by withdrawal sessions, other withdrawals and deploys can't use it
+    pub reserved_idle: u64,
+    pub reserved: [u64; 7],
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -44,6 +87,18 @@ pub struct VaultConfig {
     pub accountant: Pubkey,
     pub profit_max_unlock_time: u64,
     pub kyc_verified_only: bool,
+    pub deposit_window_end: i64,
+    pub maturity: i64,
+    pub early_exit_penalty: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InsuranceConfig {
+    /// cut of each reported profit that goes to the reserve, in bps
+    pub fee: u64,
+    /// reserve size in underlying above which the owner can withdraw
+    pub target: u64,
+    pub owner: Pubkey,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -80,6 +135,7 @@ impl Vault {
         underlying_token_acc: Pubkey,
         config: &VaultConfig
     ) -> Result<()> {
+        self.version = VAULT_VERSION;
         self.index_buffer = index.to_le_bytes();
         self.bump = [bump];
         self.key = pubkey;
@@ -94,6 +150,15 @@ impl Vault {
         self.profit_max_unlock_time = config.profit_max_unlock_time;
         self.kyc_verified_only = config.kyc_verified_only;
 
+        if config.early_exit_penalty > MAX_BPS 
+            || (config.maturity != 0 && config.deposit_window_end > config.maturity) {
+            return Err(ErrorCode::InvalidTermConfig.into());
+        }
+
+        self.deposit_window_end = config.deposit_window_end;
+        self.maturity = config.maturity;
+        self.early_exit_penalty = config.early_exit_penalty;
+
         self.is_shutdown = false;
         self.total_debt = 0;
         self.total_shares = 0;
@@ -106,6 +171,47 @@ impl Vault {
         self.deposit_limit = 0;
     }
 
+    pub fn is_deposit_window_open(&self, timestamp: i64) -> bool {
+        self.deposit_window_end == 0 || timestamp <= self.deposit_window_end
+    }
+
+    pub fn is_matured(&self, timestamp: i64) -> bool {
+        self.maturity != 0 && timestamp >= self.maturity
+    }
+
+    /// Part of the withdrawn assets kept by the vault for leaving a term vault early
+    pub fn exit_penalty(&self, assets: u64, timestamp: i64) -> Result<u64> {
+        if self.maturity == 0 || self.is_matured(timestamp) {
+            return Ok(0);
+        }
+
+        if self.early_exit_penalty == 0 {
+            return Err(ErrorCode::VaultNotMatured.into());
+        }
+
+        Ok((assets as u128 * self.early_exit_penalty as u128 / MAX_BPS as u128) as u64)
+    }
+
+    /// Term vaults shut down on their own once matured, returns true if it happened now
+    pub fn sunset(&mut self, timestamp: i64) -> bool {
+        if self.is_shutdown || !self.is_matured(timestamp) {
+            return false;
+        }
+
+        self.shutdown();
+        true
+    }
+
+    pub fn resolve_max_loss(&self, max_loss: Option<u64>) -> Result<u64> {
+        let max_loss = max_loss.unwrap_or(self.default_max_loss);
+
+        if max_loss > MAX_BPS {
+            return Err(ErrorCode::InvalidMaxLoss.into());
+        }
+
+        Ok(max_loss)
+    }
+
     pub fn handle_deposit(&mut self, amount: u64, shares: u64) {
         self.total_idle += amount;
         self.total_shares += shares;
@@ -136,6 +242,46 @@ impl Vault {
         }
     }
 
+    pub fn price_per_share(&self) -> u64 {
+        self.price_per_share_at(self.total_funds())
+    }
+
+    pub fn price_per_share_at(&self, total_funds: u64) -> u64 {
+        let one_share = 10u64.pow(SHARES_DECIMALS as u32);
+        if self.total_shares() == 0 {
+            one_share
+        } else {
+            (one_share as u128 * total_funds as u128 / self.total_shares() as u128) as u64
+        }
+    }
+
+    /// Pauses the vault if `total_funds` would price shares too far below
+    /// the last reported price, returns true if the breaker tripped now
+    pub fn check_circuit_breaker(&mut self, total_funds: u64) -> bool {
+        let last = self.last_price_per_share;
+        if self.is_paused || self.pps_drop_threshold == 0 || last == 0 {
+            return false;
+        }
+
+        let price = self.price_per_share_at(total_funds);
+        if price >= last {
+            return false;
+        }
+
+        let drop = (last - price) as u128 * MAX_BPS as u128 / last as u128;
+        if drop <= self.pps_drop_threshold as u128 {
+            return false;
+        }
+
+        self.is_paused = true;
+        true
+    }
+
+    /// Idle that is not reserved for withdrawal sessions
+    pub fn available_idle(&self) -> u64 {
+        self.total_idle - self.reserved_idle
+    }
+
     pub fn total_funds(&self) -> u64 {
         self.total_debt + self.total_idle
     }
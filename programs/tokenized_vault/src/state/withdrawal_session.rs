@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DISCRIMINATOR_LEN;
+
+/// Withdrawal spread over several transactions, the shares are escrowed
+/// while strategies are drained page by page
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct WithdrawalSession {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub shares: u64,
+    // assets the shares were worth when the session started
+    pub assets: u64,
+    pub max_loss: u64,
+    // losses taken so far from strategies
+    pub loss: u64,
+    pub started_at: i64,
+    // vault idle held for this session, released on finalize or cancel
+    pub reserved_idle: u64,
+}
+
+impl WithdrawalSession {
+    pub const LEN: usize = DISCRIMINATOR_LEN + WithdrawalSession::INIT_SPACE;
+
+    pub fn requested_assets(&self) -> u64 {
+        self.assets - self.loss
+    }
+
+    /// Assets still to be pulled into the idle for this session
+    pub fn unreserved_assets(&self) -> u64 {
+        self.requested_assets().saturating_sub(self.reserved_idle)
+    }
+}
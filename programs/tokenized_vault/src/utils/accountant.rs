@@ -2,15 +2,36 @@ use anchor_lang::prelude::*;
  
 use accountant::utils::UncheckedAccountant;
 
-pub fn report(acccountant: &UncheckedAccount, profit: u64, loss: u64) -> Result<(u64,u64)>{
+pub fn report(
+    acccountant: &UncheckedAccount, 
+    vault: Pubkey,
+    profit: u64, 
+    loss: u64,
+    current_debt: u64,
+    duration: u64,
+) -> Result<(u64,u64)>{
     let acc = acccountant.from_unchecked()?;
     acc.report(
+        vault,
         profit, 
-        loss
+        loss,
+        current_debt,
+        duration
     )
 }
 
-pub fn performance_fee(acccountant: &UncheckedAccount) -> Result<u64>{
+pub fn check_health(
+    acccountant: &UncheckedAccount, 
+    vault: Pubkey,
+    profit: u64, 
+    loss: u64,
+    current_debt: u64,
+) -> Result<()> {
     let acc = acccountant.from_unchecked()?;
-    Ok(acc.performance_fee())
-}
\ No newline at end of file
+    acc.check_health(vault, profit, loss, current_debt)
+}
+
+pub fn performance_fee(acccountant: &UncheckedAccount, vault: Pubkey) -> Result<u64>{
+    let acc = acccountant.from_unchecked()?;
+    Ok(acc.performance_fee(vault))
+}
@@ -1,90 +1,132 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::TokenAccount;
+use strategy_interface::{DepositAccounts, WithdrawAccounts};
  
 use strategy::utils::deserialize;
-use strategy::cpi::accounts::{
-    Deposit,
-    Withdraw
-};
 
+use crate::constants::STRATEGY_PROGRAMS_SEED;
+use crate::errors::ErrorCode;
+use crate::state::StrategyPrograms;
+use crate::utils::token;
+
+// Strategies of the built-in strategy program are read directly,
+// any other approved program is called through the strategy interface.
+fn is_builtin(strategy_acc: &AccountInfo) -> bool {
+    *strategy_acc.owner == strategy::ID
+}
+
+/// Moves the assets to the strategy before it records the deposit, so the strategy
+/// never gets an account of the vault it could move funds from
 pub fn deposit<'a>(
     strategy: AccountInfo<'a>,
     vault: AccountInfo<'a>,
     underlying_token_account: AccountInfo<'a>,
-    vault_token_account: AccountInfo<'a>,
+    vault_token_account: &mut InterfaceAccount<'a, TokenAccount>,
     token_program: AccountInfo<'a>,
-    strategy_program: AccountInfo<'a>,
     assets_to_deposit: u64,
     seeds: &[&[u8]],
 ) -> Result<()> {
-    // Perform the CPI deposit with pre-extracted data
-    strategy::cpi::deposit(
-        CpiContext::new_with_signer(
-            strategy_program,
-            Deposit {
-                strategy,
-                signer: vault,
-                underlying_token_account,
-                vault_token_account,
-                token_program,
-            },
-            &[&seeds],  // Pass in the seeds from the previously loaded vault
-        ),
+    let pre_balance = vault_token_account.amount;
+    let program_id = *strategy.owner;
+
+    token::transfer_with_signer(
+        token_program,
+        vault_token_account.to_account_info(),
+        underlying_token_account.clone(),
+        vault.clone(),
+        assets_to_deposit,
+        seeds,
+    )?;
+
+    strategy_interface::deposit(
+        &program_id,
+        DepositAccounts {
+            strategy,
+            underlying_token_account,
+            vault,
+        },
         assets_to_deposit,
-    )
+        &[seeds],
+    )?;
+
+    vault_token_account.reload()?;
+    if pre_balance.checked_sub(vault_token_account.amount) != Some(assets_to_deposit) {
+        return Err(ErrorCode::UnexpectedStrategyTransfer.into());
+    }
+
+    Ok(())
 }
 
+/// The strategy has to send exactly the requested assets and leave
+/// the vault token account otherwise untouched
 pub fn withdraw<'a>(
     strategy: AccountInfo<'a>,
     vault: AccountInfo<'a>,
     underlying_token_account: AccountInfo<'a>,
     vault_token_account: &mut InterfaceAccount<'a, TokenAccount>,
     token_program: AccountInfo<'a>,
-    strategy_program: AccountInfo<'a>,
     assets_to_withdraw: u64,
     seeds: &[&[&[u8]]],
     remaining_accounts: Vec<AccountInfo<'a>>,
-) -> Result<u64> {
+) -> Result<()> {
     let pre_balance = vault_token_account.amount;
+    let program_id = *strategy.owner;
+    let vault_key = vault.key();
 
-    let mut context = CpiContext::new_with_signer(
-        strategy_program, 
-        Withdraw {
+    strategy_interface::withdraw(
+        &program_id,
+        WithdrawAccounts {
             strategy,
             underlying_token_account,
-            signer: vault,
+            vault,
             vault_token_account: vault_token_account.to_account_info(),
             token_program,
         },
+        assets_to_withdraw,
         seeds,
-    );
-    
-    context.remaining_accounts = remaining_accounts;
-
-    strategy::cpi::withdraw(context, assets_to_withdraw)?;
+        remaining_accounts,
+    )?;
 
     vault_token_account.reload()?;
-    let post_balance = vault_token_account.amount;
+    if vault_token_account.amount.checked_sub(pre_balance) != Some(assets_to_withdraw)
+        || vault_token_account.owner != vault_key
+        || vault_token_account.delegate.is_some()
+        || vault_token_account.close_authority.is_some()
+    {
+        return Err(ErrorCode::UnexpectedStrategyTransfer.into());
+    }
 
-    Ok(post_balance - pre_balance)
+    Ok(())
 }
 
 pub fn get_vault(strategy_acc: &AccountInfo) -> Result<Pubkey> {
+    if !is_builtin(strategy_acc) {
+        return strategy_interface::strategy_vault(strategy_acc.owner, strategy_acc);
+    }
     let strategy = deserialize(strategy_acc)?;
     Ok(strategy.vault())
 }
 
 pub fn get_max_withdraw(strategy_acc: &AccountInfo) -> Result<u64> {
+    if !is_builtin(strategy_acc) {
+        return strategy_interface::max_withdraw(strategy_acc.owner, strategy_acc);
+    }
     let strategy = deserialize(strategy_acc)?;
     Ok(strategy.available_withdraw())
 }
 
 pub fn get_max_deposit(strategy_acc: &AccountInfo) -> Result<u64> {
+    if !is_builtin(strategy_acc) {
+        return strategy_interface::max_deposit(strategy_acc.owner, strategy_acc);
+    }
     let strategy = deserialize(strategy_acc)?;
     Ok(strategy.available_deposit())
 }
 
 pub fn get_total_assets(strategy_acc: &AccountInfo) -> Result<u64> {
+    if !is_builtin(strategy_acc) {
+        return strategy_interface::total_assets(strategy_acc.owner, strategy_acc);
+    }
     let strategy = deserialize(strategy_acc)?;
     Ok(strategy.total_assets())
 }
@@ -108,4 +150,25 @@ pub fn assess_share_of_unrealised_losses(
     let losses_user_share = assets_needed - numerator / strategy_current_debt;
 
     Ok(losses_user_share)
-}
\ No newline at end of file
+}
+
+/// Non built-in programs have to be approved in the vault strategy programs account,
+/// looked up in the remaining accounts
+pub fn validate_strategy_program(vault: &Pubkey, program: &Pubkey, remaining_accounts: &[AccountInfo]) -> Result<()> {
+    let expected_key = Pubkey::find_program_address(
+        &[STRATEGY_PROGRAMS_SEED.as_bytes(), vault.as_ref()],
+        &crate::ID,
+    ).0;
+
+    let strategy_programs_acc = remaining_accounts
+        .iter()
+        .find(|acc| acc.key() == expected_key && *acc.owner == crate::ID)
+        .ok_or(ErrorCode::StrategyProgramNotApproved)?;
+
+    let strategy_programs = StrategyPrograms::try_deserialize(&mut &strategy_programs_acc.try_borrow_data()?[..])?;
+    if !strategy_programs.is_approved(program) {
+        return Err(ErrorCode::StrategyProgramNotApproved.into());
+    }
+
+    Ok(())
+}
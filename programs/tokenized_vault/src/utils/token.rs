@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer, MintTo, Burn};
+use anchor_spl::token::{self, Transfer, MintTo, Burn, CloseAccount, SyncNative};
 
 pub fn get_athority<'a>(
     token_program: AccountInfo<'a>
@@ -113,3 +113,54 @@ pub fn burn_with_signer<'a>(
     )
 }
 
+pub fn close_account<'a>(
+    token_program: AccountInfo<'a>,
+    account: AccountInfo<'a>,
+    destination: AccountInfo<'a>,
+    authority: AccountInfo<'a>,
+) -> Result<()> {
+    token::close_account(
+        CpiContext::new(
+            token_program,
+            CloseAccount {
+                account,
+                destination,
+                authority
+            }
+        )
+    )
+}
+
+pub fn close_account_with_signer<'a>(
+    token_program: AccountInfo<'a>,
+    account: AccountInfo<'a>,
+    destination: AccountInfo<'a>,
+    authority: AccountInfo<'a>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    token::close_account(
+        CpiContext::new_with_signer(
+            token_program,
+            CloseAccount {
+                account,
+                destination,
+                authority
+            },
+            &[seeds]
+        )
+    )
+}
+
+pub fn sync_native<'a>(
+    token_program: AccountInfo<'a>,
+    account: AccountInfo<'a>,
+) -> Result<()> {
+    token::sync_native(
+        CpiContext::new(
+            token_program,
+            SyncNative {
+                account
+            }
+        )
+    )
+}
@@ -214,7 +214,7 @@ describe("tokenized_vault with trade-fi strategy", () => {
     const airdropSignature = await AnchorProvider.env().connection.requestAirdrop(feeRecipient.publicKey, 10e9);
     await AnchorProvider.env().connection.confirmTransaction(airdropSignature);
 
-    await strategyProgram.methods.setFeeManager(feeRecipient.publicKey)
+    await strategyProgram.methods.proposeFeeManager(feeRecipient.publicKey)
       .accounts({
         strategy,
         signer: admin.publicKey,
@@ -222,6 +222,15 @@ describe("tokenized_vault with trade-fi strategy", () => {
       .signers([admin])
       .rpc();
 
+    await strategyProgram.methods.acceptFeeManagement()
+      .accounts({
+        strategy,
+        payer: admin.publicKey,
+        signer: feeRecipient.publicKey,
+      })
+      .signers([feeRecipient])
+      .rpc();
+
     const strategyAccount = await strategyProgram.account.tradeFintechStrategy.fetch(strategy);
     assert.strictEqual(strategyAccount.feeData.feeManager.toString(), feeRecipient.publicKey.toString());
   });
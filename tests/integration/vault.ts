@@ -21,6 +21,15 @@ const ROLES = {
   KYC_VERIFIED: new BN(6),
 }
 
+type VaultWithStrategy = {
+  vault: web3.PublicKey;
+  sharesMint: web3.PublicKey;
+  vaultTokenAccount: web3.PublicKey;
+  strategy: web3.PublicKey;
+  strategyTokenAccount: web3.PublicKey;
+  strategyData: web3.PublicKey;
+}
+
 describe("tokenized_vault", () => {
   // Configure the client to use the local cluster.
   setProvider(AnchorProvider.env());
@@ -280,6 +289,9 @@ describe("tokenized_vault", () => {
       accountant: accountant,
       profitMaxUnlockTime: new BN(0),
       kycVerifiedOnly: true,
+      depositWindowEnd: new BN(0),
+      maturity: new BN(0),
+      earlyExitPenalty: new BN(0),
     };
 
     const sharesConfig = {
@@ -384,6 +396,191 @@ describe("tokenized_vault", () => {
     console.log("Fee recipient:", genericAccountant.feeRecipient.toString());
   });
 
+  it("set fee change delay", async () => {
+    await accountantProgram.methods.setFeeChangeDelay(new BN(5))
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    assert.strictEqual(genericAccountant.feeChangeDelay.toString(), '5');
+    assert.isNull(genericAccountant.pendingFeeChangeDelay);
+  });
+
+  it("set fee - increase is timelocked", async () => {
+    try {
+      await accountantProgram.methods.setFee(new BN(600))
+        .accounts({
+          accountant: accountant,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: FeeIncreaseTimelocked");
+    }
+  });
+
+  it("propose fee - apply before the delay", async () => {
+    let genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    const config = { ...genericAccountant.defaultConfig, performanceFee: new BN(600) };
+
+    await accountantProgram.methods.proposeFee(null, config)
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    assert.strictEqual(genericAccountant.pendingFeeConfig.config.performanceFee.toString(), '600');
+    assert.isNull(genericAccountant.pendingFeeConfig.vault);
+
+    try {
+      await accountantProgram.methods.applyFee()
+        .accounts({
+          accountant: accountant,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: FeeChangeDelayNotPassed");
+    }
+  });
+
+  it("propose fee - rejected while a proposal is pending", async () => {
+    const genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    const config = { ...genericAccountant.defaultConfig, performanceFee: new BN(700) };
+
+    try {
+      await accountantProgram.methods.proposeFee(null, config)
+        .accounts({
+          accountant: accountant,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: FeeChangePending");
+    }
+  });
+
+  it("cancel fee", async () => {
+    await accountantProgram.methods.cancelFee()
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    assert.isNull(genericAccountant.pendingFeeConfig);
+    assert.strictEqual(genericAccountant.performanceFee.toString(), '500');
+
+    try {
+      await accountantProgram.methods.applyFee()
+        .accounts({
+          accountant: accountant,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: NoPendingFeeChange");
+    }
+  });
+
+  it("propose fee - apply after the delay", async () => {
+    let genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    const config = { ...genericAccountant.defaultConfig, performanceFee: new BN(600) };
+
+    await accountantProgram.methods.proposeFee(null, config)
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await new Promise(resolve => setTimeout(resolve, 7 * 1000));
+
+    await accountantProgram.methods.applyFee()
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    assert.isNull(genericAccountant.pendingFeeConfig);
+    assert.strictEqual(genericAccountant.performanceFee.toString(), '600');
+    assert.strictEqual(genericAccountant.defaultConfig.performanceFee.toString(), '600');
+
+    // decreases are not timelocked
+    await accountantProgram.methods.setFee(new BN(500))
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    assert.strictEqual(genericAccountant.performanceFee.toString(), '500');
+  });
+
+  it("set fee change delay - decrease is timelocked", async () => {
+    await accountantProgram.methods.setFeeChangeDelay(new BN(0))
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    let genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    assert.strictEqual(genericAccountant.feeChangeDelay.toString(), '5');
+    assert.strictEqual(genericAccountant.pendingFeeChangeDelay.delay.toString(), '0');
+
+    try {
+      await accountantProgram.methods.applyFeeChangeDelay()
+        .accounts({
+          accountant: accountant,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: FeeChangeDelayNotPassed");
+    }
+
+    await new Promise(resolve => setTimeout(resolve, 7 * 1000));
+
+    await accountantProgram.methods.applyFeeChangeDelay()
+      .accounts({
+        accountant: accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    genericAccountant = await accountantProgram.account.genericAccountant.fetch(accountant);
+    assert.strictEqual(genericAccountant.feeChangeDelay.toString(), '0');
+    assert.isNull(genericAccountant.pendingFeeChangeDelay);
+  });
+
   it("Initializes the strategy", async () => {
     await strategyProgram.methods.initialize()
       .accounts({
@@ -470,24 +667,241 @@ describe("tokenized_vault", () => {
     const airdropSignature = await AnchorProvider.env().connection.requestAirdrop(feeRecipient.publicKey, 10e9);
     await AnchorProvider.env().connection.confirmTransaction(airdropSignature);
 
-    await strategyProgram.methods.setFeeManager(strategyFeeManager.publicKey)
+    await strategyProgram.methods.proposeFeeManager(strategyFeeManager.publicKey)
+      .accounts({
+        strategy,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await strategyProgram.methods.acceptFeeManagement()
+      .accounts({
+        strategy,
+        payer: admin.publicKey,
+        signer: strategyFeeManager.publicKey,
+      })
+      .signers([strategyFeeManager])
+      .rpc();
+
+    const strategyAccount = await strategyProgram.account.simpleStrategy.fetch(strategy);
+    assert.strictEqual(strategyAccount.feeData.feeManager.toString(), strategyFeeManager.publicKey.toString());
+  });
+
+  it("cancel fee manager proposal", async () => {
+    await strategyProgram.methods.proposeFeeManager(feeRecipient.publicKey)
+      .accounts({
+        strategy,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await strategyProgram.methods.cancelFeeManagerProposal()
       .accounts({
         strategy,
+        payer: admin.publicKey,
         signer: admin.publicKey,
       })
       .signers([admin])
       .rpc();
 
+    const pendingFeeManager = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_fee_manager"), strategy.toBuffer()],
+      strategyProgram.programId,
+    )[0];
+    const pendingAccount = await strategyProgram.account.pendingTransfer.fetchNullable(pendingFeeManager);
+    assert.isNull(pendingAccount);
+
+    try {
+      await strategyProgram.methods.acceptFeeManagement()
+        .accounts({
+          strategy,
+          payer: admin.publicKey,
+          signer: feeRecipient.publicKey,
+        })
+        .signers([feeRecipient])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: AccountNotInitialized");
+    }
+
     const strategyAccount = await strategyProgram.account.simpleStrategy.fetch(strategy);
     assert.strictEqual(strategyAccount.feeData.feeManager.toString(), strategyFeeManager.publicKey.toString());
   });
 
+  it("cancel manager proposal", async () => {
+    const newManager = web3.Keypair.generate();
+
+    await strategyProgram.methods.proposeManager(newManager.publicKey)
+      .accounts({
+        strategy,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    // only the current manager can cancel
+    try {
+      await strategyProgram.methods.cancelManagerProposal()
+        .accounts({
+          strategy,
+          payer: admin.publicKey,
+          signer: newManager.publicKey,
+        })
+        .signers([newManager])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: AccessDenied");
+    }
+
+    await strategyProgram.methods.cancelManagerProposal()
+      .accounts({
+        strategy,
+        payer: admin.publicKey,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const pendingManager = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_manager"), strategy.toBuffer()],
+      strategyProgram.programId,
+    )[0];
+    const pendingAccount = await strategyProgram.account.pendingTransfer.fetchNullable(pendingManager);
+    assert.isNull(pendingAccount);
+
+    try {
+      await strategyProgram.methods.acceptManagement()
+        .accounts({
+          strategy,
+          payer: admin.publicKey,
+          signer: newManager.publicKey,
+        })
+        .signers([newManager])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: AccountNotInitialized");
+    }
+
+    const strategyAccount = await strategyProgram.account.simpleStrategy.fetch(strategy);
+    assert.strictEqual(strategyAccount.manager.toString(), admin.publicKey.toString());
+  });
+
+  it("add strategy - program not approved", async () => {
+    // any account owned by a program other than the built-in strategy program
+    try {
+      await vaultProgram.methods.addStrategy(new BN(1000000000))
+        .accounts({
+          vault,
+          strategy: accountant,
+          signer: admin.publicKey,
+          strategyProgram: accountantProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: StrategyProgramNotApproved");
+    }
+  });
+
+  it("approve strategy program", async () => {
+    await vaultProgram.methods.approveStrategyProgram()
+      .accounts({
+        vault,
+        strategyProgram: accountantProgram.programId,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const strategyPrograms = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("strategy_programs"), vault.toBuffer()],
+      vaultProgram.programId,
+    )[0];
+    const strategyProgramsAccount = await vaultProgram.account.strategyPrograms.fetch(strategyPrograms);
+    assert.strictEqual(strategyProgramsAccount.programs.length, 1);
+    assert.ok(strategyProgramsAccount.programs[0].equals(accountantProgram.programId));
+  });
+
+  it("approve strategy program - built-in program", async () => {
+    try {
+      await vaultProgram.methods.approveStrategyProgram()
+        .accounts({
+          vault,
+          strategyProgram: strategyProgram.programId,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: InvalidStrategyProgram");
+    }
+  });
+
+  it("approve strategy program - unauthorized", async () => {
+    try {
+      await vaultProgram.methods.approveStrategyProgram()
+        .accounts({
+          vault,
+          strategyProgram: accountantProgram.programId,
+          signer: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("AnchorError caused by account: roles. Error Code: AccountNotInitialized");
+    }
+  });
+
+  it("revoke strategy program", async () => {
+    await vaultProgram.methods.revokeStrategyProgram(accountantProgram.programId)
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const strategyPrograms = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("strategy_programs"), vault.toBuffer()],
+      vaultProgram.programId,
+    )[0];
+    const strategyProgramsAccount = await vaultProgram.account.strategyPrograms.fetch(strategyPrograms);
+    assert.strictEqual(strategyProgramsAccount.programs.length, 0);
+
+    try {
+      await vaultProgram.methods.addStrategy(new BN(1000000000))
+        .accounts({
+          vault,
+          strategy: accountant,
+          signer: admin.publicKey,
+          strategyProgram: accountantProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: strategyPrograms, isWritable: false, isSigner: false },
+        ])
+        .signers([admin])
+        .rpc();
+      assert.fail("Expected error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain("Error Code: StrategyProgramNotApproved");
+    }
+  });
+
   it("Adds a strategy to the vault", async () => {
     await vaultProgram.methods.addStrategy(new BN(1000000000))
       .accounts({
         vault,
         strategy,
         signer: admin.publicKey,
+        strategyProgram: strategyProgram.programId,
       })
       .signers([admin])
       .rpc();
@@ -568,7 +982,9 @@ describe("tokenized_vault", () => {
       .accounts({
         vault,
         strategy,
+        strategyTokenAccount,
         signer: admin.publicKey,
+        strategyProgram: strategyProgram.programId,
       })
       .signers([admin])
       .rpc();
@@ -605,7 +1021,9 @@ describe("tokenized_vault", () => {
       .accounts({
         vault,
         strategy,
+        strategyTokenAccount,
         signer: admin.publicKey,
+        strategyProgram: strategyProgram.programId,
       })
       .signers([admin])
       .rpc();
@@ -670,6 +1088,7 @@ describe("tokenized_vault", () => {
         { pubkey: strategy, isWritable: true, isSigner: false },
         { pubkey: strategyTokenAccount, isWritable: true, isSigner: false },
         { pubkey: strategyData, isWritable: true, isSigner: false },
+        { pubkey: strategyProgram.programId, isWritable: false, isSigner: false },
 
       ])
       .signers([user])
@@ -761,6 +1180,7 @@ describe("tokenized_vault", () => {
         { pubkey: strategy, isWritable: true, isSigner: false },
         { pubkey: strategyTokenAccount, isWritable: true, isSigner: false },
         { pubkey: strategyData, isWritable: true, isSigner: false },
+        { pubkey: strategyProgram.programId, isWritable: false, isSigner: false },
       ])
       .signers([newOwner])
       .rpc();
@@ -848,6 +1268,7 @@ describe("tokenized_vault", () => {
         { pubkey: strategy, isWritable: true, isSigner: false },
         { pubkey: strategyTokenAccount, isWritable: true, isSigner: false },
         { pubkey: strategyData, isWritable: true, isSigner: false },
+        { pubkey: strategyProgram.programId, isWritable: false, isSigner: false },
       ])
       .signers([user])
       .rpc();
@@ -889,6 +1310,7 @@ describe("tokenized_vault", () => {
         { pubkey: strategy, isWritable: true, isSigner: false },
         { pubkey: strategyTokenAccount, isWritable: true, isSigner: false },
         { pubkey: strategyData, isWritable: true, isSigner: false },
+        { pubkey: strategyProgram.programId, isWritable: false, isSigner: false },
       ])
       .signers([feeRecipient])
       .rpc();
@@ -1026,7 +1448,9 @@ describe("tokenized_vault", () => {
       .accounts({
         vault,
         strategy,
+        strategyTokenAccount,
         signer: admin.publicKey,
+        strategyProgram: strategyProgram.programId,
       })
       .signers([admin])
       .rpc();
@@ -1117,6 +1541,7 @@ describe("tokenized_vault", () => {
         vault,
         strategy,
         signer: admin.publicKey,
+        strategyProgram: strategyProgram.programId,
       })
       .signers([admin])
       .rpc();
@@ -1180,4 +1605,545 @@ describe("tokenized_vault", () => {
     // const vaultAccount = await vaultProgram.account.vault.fetch(vault);
     // assert.isTrue(vaultAccount.isClosed);
   });
+
+  // each of the scenarios below runs on its own vault with a simple strategy
+  async function initVaultWithStrategy(symbol: string): Promise<VaultWithStrategy> {
+    const provider = AnchorProvider.env();
+
+    const config = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      vaultProgram.programId,
+    )[0];
+    const vaultIndex = (await vaultProgram.account.config.fetch(config)).nextVaultIndex;
+
+    const newVault = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vault"),
+        Buffer.from(new Uint8Array(new BigUint64Array([BigInt(vaultIndex.toString())]).buffer))
+      ],
+      vaultProgram.programId
+    )[0];
+
+    const newSharesMint = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("shares"), newVault.toBuffer()],
+      vaultProgram.programId
+    )[0];
+
+    const [metadataAddress] = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(METADATA_SEED),
+        TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+        newSharesMint.toBuffer(),
+      ],
+      TOKEN_METADATA_PROGRAM_ID
+    );
+
+    await vaultProgram.methods.initVault({
+      depositLimit: new BN(1000000000),
+      minUserDeposit: new BN(0),
+      accountant: accountant,
+      profitMaxUnlockTime: new BN(0),
+      kycVerifiedOnly: false,
+      depositWindowEnd: new BN(0),
+      maturity: new BN(0),
+      earlyExitPenalty: new BN(0),
+    })
+      .accounts({
+        underlyingMint,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await vaultProgram.methods.initVaultShares(vaultIndex, {
+      name: "Polite Viking Token",
+      symbol,
+      uri: "https://gist.githubusercontent.com/vito-kovalione/08b86d3c67440070a8061ae429572494/raw/833e3d5f5988c18dce2b206a74077b2277e13ab6/PVT.json",
+    })
+      .accounts({
+        metadata: metadataAddress,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    // the accountant takes its fees in the vault shares
+    await token.getOrCreateAssociatedTokenAccount(provider.connection, admin, newSharesMint, accountant, true);
+
+    const strategyConfig = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      strategyProgram.programId,
+    )[0];
+    const strategyIndex = (await strategyProgram.account.config.fetch(strategyConfig)).nextStrategyIndex;
+
+    const newStrategy = web3.PublicKey.findProgramAddressSync(
+      [
+        newVault.toBuffer(),
+        Buffer.from(new Uint8Array(new BigUint64Array([BigInt(strategyIndex.toString())]).buffer))
+      ],
+      strategyProgram.programId
+    )[0];
+
+    const newStrategyTokenAccount = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("underlying"), newStrategy.toBuffer()],
+      strategyProgram.programId,
+    )[0];
+
+    const strategyConfigBytes = Buffer.from(borsh.serialize(SimpleStrategyConfigSchema, new SimpleStrategyConfig({
+      depositLimit: new BN(1000000000),
+      performanceFee: new BN(0),
+      feeManager: admin.publicKey
+    })));
+
+    await strategyProgram.methods.initStrategy({ simple: {} }, strategyConfigBytes)
+      .accounts({
+        underlyingMint,
+        vault: newVault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await vaultProgram.methods.addStrategy(new BN(1000000000))
+      .accounts({
+        vault: newVault,
+        strategy: newStrategy,
+        signer: admin.publicKey,
+        strategyProgram: strategyProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const newStrategyData = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("strategy_data"), newVault.toBuffer(), newStrategy.toBuffer()],
+      vaultProgram.programId,
+    )[0];
+
+    return {
+      vault: newVault,
+      sharesMint: newSharesMint,
+      vaultTokenAccount: web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("underlying"), newVault.toBuffer()],
+        vaultProgram.programId,
+      )[0],
+      strategy: newStrategy,
+      strategyTokenAccount: newStrategyTokenAccount,
+      strategyData: newStrategyData,
+    };
+  }
+
+  describe("tranches", () => {
+    let trancheUser: web3.Keypair;
+    let trancheUserTokenAccount: web3.PublicKey;
+    let seniorAccount: web3.PublicKey;
+    let juniorAccount: web3.PublicKey;
+    let tranches: web3.PublicKey;
+    let accounts: VaultWithStrategy;
+
+    before(async () => {
+      const provider = AnchorProvider.env();
+
+      trancheUser = web3.Keypair.generate();
+      const airdropSignature = await provider.connection.requestAirdrop(trancheUser.publicKey, 10e9);
+      await provider.connection.confirmTransaction(airdropSignature);
+
+      accounts = await initVaultWithStrategy("PVTT");
+
+      trancheUserTokenAccount = await token.createAccount(provider.connection, trancheUser, underlyingMint, trancheUser.publicKey);
+      await token.mintTo(provider.connection, admin, underlyingMint, trancheUserTokenAccount, admin.publicKey, 1000);
+
+      tranches = web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("tranches"), accounts.vault.toBuffer()],
+        vaultProgram.programId,
+      )[0];
+    });
+
+    it("init tranches", async () => {
+      // no senior yield, the junior tranche takes all the losses first
+      await vaultProgram.methods.initTranches(new BN(0), new BN(5000))
+        .accounts({
+          vault: accounts.vault,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const tranchesAccount = await vaultProgram.account.tranches.fetch(tranches);
+      assert.strictEqual(tranchesAccount.minSubordination.toString(), '5000');
+
+      const vaultAccount = await vaultProgram.account.vault.fetch(accounts.vault);
+      assert.isTrue(vaultAccount.isTranched);
+
+      const provider = AnchorProvider.env();
+      seniorAccount = await token.createAccount(provider.connection, trancheUser, tranchesAccount.seniorMint, trancheUser.publicKey);
+      juniorAccount = await token.createAccount(provider.connection, trancheUser, tranchesAccount.juniorMint, trancheUser.publicKey);
+    });
+
+    it("deposit tranche", async () => {
+      const provider = AnchorProvider.env();
+
+      await vaultProgram.methods.depositTranche({ junior: {} }, new BN(100))
+        .accounts({
+          vault: accounts.vault,
+          userTokenAccount: trancheUserTokenAccount,
+          userTrancheAccount: juniorAccount,
+          user: trancheUser.publicKey,
+        })
+        .signers([trancheUser])
+        .rpc();
+
+      await vaultProgram.methods.depositTranche({ senior: {} }, new BN(100))
+        .accounts({
+          vault: accounts.vault,
+          userTokenAccount: trancheUserTokenAccount,
+          userTrancheAccount: seniorAccount,
+          user: trancheUser.publicKey,
+        })
+        .signers([trancheUser])
+        .rpc();
+
+      const tranchesAccount = await vaultProgram.account.tranches.fetch(tranches);
+      assert.strictEqual(tranchesAccount.juniorAssets.toString(), '100');
+      assert.strictEqual(tranchesAccount.seniorAssets.toString(), '100');
+      assert.strictEqual(tranchesAccount.poolShares.toString(), '200');
+
+      const juniorAccountInfo = await token.getAccount(provider.connection, juniorAccount);
+      assert.strictEqual(juniorAccountInfo.amount.toString(), '100');
+      const seniorAccountInfo = await token.getAccount(provider.connection, seniorAccount);
+      assert.strictEqual(seniorAccountInfo.amount.toString(), '100');
+    });
+
+    it("deposit tranche - subordination too low", async () => {
+      try {
+        await vaultProgram.methods.depositTranche({ senior: {} }, new BN(10))
+          .accounts({
+            vault: accounts.vault,
+            userTokenAccount: trancheUserTokenAccount,
+            userTrancheAccount: seniorAccount,
+            user: trancheUser.publicKey,
+          })
+          .signers([trancheUser])
+          .rpc();
+        assert.fail("Expected error was not thrown");
+      } catch (err) {
+        expect(err.message).to.contain("Error Code: SubordinationTooLow");
+      }
+    });
+
+    it("deposit - tranched vault", async () => {
+      const provider = AnchorProvider.env();
+      const trancheUserSharesAccount = await token.createAccount(provider.connection, trancheUser, accounts.sharesMint, trancheUser.publicKey);
+
+      try {
+        await vaultProgram.methods.deposit(new BN(10))
+          .accounts({
+            vault: accounts.vault,
+            user: trancheUser.publicKey,
+            userTokenAccount: trancheUserTokenAccount,
+            userSharesAccount: trancheUserSharesAccount,
+          })
+          .signers([trancheUser])
+          .rpc();
+        assert.fail("Expected error was not thrown");
+      } catch (err) {
+        expect(err.message).to.contain("Error Code: TranchedVault");
+      }
+    });
+
+    it("withdraw tranche", async () => {
+      const provider = AnchorProvider.env();
+
+      await vaultProgram.methods.withdrawTranche({ senior: {} }, new BN(50), null, { accountsMap: [] })
+        .accounts({
+          vault: accounts.vault,
+          userTokenAccount: trancheUserTokenAccount,
+          userTrancheAccount: seniorAccount,
+          user: trancheUser.publicKey,
+        })
+        .signers([trancheUser])
+        .rpc();
+
+      const tranchesAccount = await vaultProgram.account.tranches.fetch(tranches);
+      assert.strictEqual(tranchesAccount.seniorAssets.toString(), '50');
+      assert.strictEqual(tranchesAccount.juniorAssets.toString(), '100');
+      assert.strictEqual(tranchesAccount.poolShares.toString(), '150');
+
+      const seniorAccountInfo = await token.getAccount(provider.connection, seniorAccount);
+      assert.strictEqual(seniorAccountInfo.amount.toString(), '50');
+
+      const userTokenAccountInfo = await token.getAccount(provider.connection, trancheUserTokenAccount);
+      assert.strictEqual(userTokenAccountInfo.amount.toString(), '850');
+    });
+
+    it("loss wipes out the junior tranche", async () => {
+      await vaultProgram.methods.updateDebt(new BN(150))
+        .accounts({
+          vault: accounts.vault,
+          strategy: accounts.strategy,
+          strategyTokenAccount: accounts.strategyTokenAccount,
+          signer: admin.publicKey,
+          strategyProgram: strategyProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      await strategyProgram.methods.reportLoss(new BN(100))
+        .accounts({
+          strategy: accounts.strategy,
+          signer: admin.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: adminTokenAccount, isWritable: true, isSigner: false },
+        ])
+        .signers([admin])
+        .rpc();
+
+      await vaultProgram.methods.processReport()
+        .accounts({
+          vault: accounts.vault,
+          strategy: accounts.strategy,
+          accountant,
+          signer: admin.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: tranches, isWritable: true, isSigner: false },
+        ])
+        .signers([admin])
+        .rpc();
+
+      const tranchesAccount = await vaultProgram.account.tranches.fetch(tranches);
+      assert.strictEqual(tranchesAccount.seniorAssets.toString(), '50');
+      assert.strictEqual(tranchesAccount.juniorAssets.toString(), '0');
+    });
+
+    it("deposit tranche - wiped out tranche", async () => {
+      // the junior shares are still outstanding but worth nothing
+      try {
+        await vaultProgram.methods.depositTranche({ junior: {} }, new BN(100))
+          .accounts({
+            vault: accounts.vault,
+            userTokenAccount: trancheUserTokenAccount,
+            userTrancheAccount: juniorAccount,
+            user: trancheUser.publicKey,
+          })
+          .signers([trancheUser])
+          .rpc();
+        assert.fail("Expected error was not thrown");
+      } catch (err) {
+        expect(err.message).to.contain("Error Code: TrancheWipedOut");
+      }
+    });
+  });
+
+  describe("withdrawal sessions", () => {
+    let sessionUser: web3.Keypair;
+    let sessionUserTokenAccount: web3.PublicKey;
+    let sessionUserSharesAccount: web3.PublicKey;
+    let session: web3.PublicKey;
+    let accounts: VaultWithStrategy;
+
+    const remainingAccountsMap = {
+      accountsMap: [
+        {
+          strategyAcc: new BN(0),
+          strategyTokenAccount: new BN(1),
+          strategyData: new BN(2),
+          remainingAccounts: [new BN(0)],
+        }]
+    };
+
+    const strategyRemainingAccounts = () => [
+      { pubkey: accounts.strategy, isWritable: true, isSigner: false },
+      { pubkey: accounts.strategyTokenAccount, isWritable: true, isSigner: false },
+      { pubkey: accounts.strategyData, isWritable: true, isSigner: false },
+      { pubkey: strategyProgram.programId, isWritable: false, isSigner: false },
+    ];
+
+    before(async () => {
+      const provider = AnchorProvider.env();
+
+      sessionUser = web3.Keypair.generate();
+      const airdropSignature = await provider.connection.requestAirdrop(sessionUser.publicKey, 10e9);
+      await provider.connection.confirmTransaction(airdropSignature);
+
+      accounts = await initVaultWithStrategy("PVTW");
+
+      sessionUserTokenAccount = await token.createAccount(provider.connection, sessionUser, underlyingMint, sessionUser.publicKey);
+      sessionUserSharesAccount = await token.createAccount(provider.connection, sessionUser, accounts.sharesMint, sessionUser.publicKey);
+      await token.mintTo(provider.connection, admin, underlyingMint, sessionUserTokenAccount, admin.publicKey, 1000);
+
+      session = web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("withdrawal_session"), accounts.vault.toBuffer(), sessionUser.publicKey.toBuffer()],
+        vaultProgram.programId,
+      )[0];
+
+      await vaultProgram.methods.deposit(new BN(100))
+        .accounts({
+          vault: accounts.vault,
+          user: sessionUser.publicKey,
+          userTokenAccount: sessionUserTokenAccount,
+          userSharesAccount: sessionUserSharesAccount,
+        })
+        .signers([sessionUser])
+        .rpc();
+
+      await vaultProgram.methods.updateDebt(new BN(80))
+        .accounts({
+          vault: accounts.vault,
+          strategy: accounts.strategy,
+          strategyTokenAccount: accounts.strategyTokenAccount,
+          signer: admin.publicKey,
+          strategyProgram: strategyProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("start withdraw", async () => {
+      const provider = AnchorProvider.env();
+
+      await vaultProgram.methods.startWithdraw(new BN(60), null)
+        .accounts({
+          vault: accounts.vault,
+          userSharesAccount: sessionUserSharesAccount,
+          user: sessionUser.publicKey,
+        })
+        .signers([sessionUser])
+        .rpc();
+
+      const sessionAccount = await vaultProgram.account.withdrawalSession.fetch(session);
+      assert.strictEqual(sessionAccount.shares.toString(), '60');
+      assert.strictEqual(sessionAccount.assets.toString(), '60');
+      assert.strictEqual(sessionAccount.reservedIdle.toString(), '0');
+
+      const userSharesAccountInfo = await token.getAccount(provider.connection, sessionUserSharesAccount);
+      assert.strictEqual(userSharesAccountInfo.amount.toString(), '40');
+    });
+
+    it("start withdraw - session already open", async () => {
+      try {
+        await vaultProgram.methods.startWithdraw(new BN(10), null)
+          .accounts({
+            vault: accounts.vault,
+            userSharesAccount: sessionUserSharesAccount,
+            user: sessionUser.publicKey,
+          })
+          .signers([sessionUser])
+          .rpc();
+        assert.fail("Expected error was not thrown");
+      } catch (err) {
+        // the session account can only be created once
+        expect(err.message).to.contain("custom program error: 0x0");
+      }
+    });
+
+    it("finalize withdraw - idle does not cover the session", async () => {
+      try {
+        await vaultProgram.methods.finalizeWithdraw()
+          .accounts({
+            vault: accounts.vault,
+            userTokenAccount: sessionUserTokenAccount,
+            user: sessionUser.publicKey,
+          })
+          .signers([sessionUser])
+          .rpc();
+        assert.fail("Expected error was not thrown");
+      } catch (err) {
+        expect(err.message).to.contain("Error Code: InsufficientFunds");
+      }
+    });
+
+    it("continue withdraw", async () => {
+      await vaultProgram.methods.continueWithdraw(remainingAccountsMap)
+        .accounts({
+          vault: accounts.vault,
+          user: sessionUser.publicKey,
+        })
+        .remainingAccounts(strategyRemainingAccounts())
+        .signers([sessionUser])
+        .rpc();
+
+      // 20 were idle already, the other 40 are pulled from the strategy
+      const vaultAccount = await vaultProgram.account.vault.fetch(accounts.vault);
+      assert.strictEqual(vaultAccount.totalIdle.toString(), '60');
+      assert.strictEqual(vaultAccount.totalDebt.toString(), '40');
+      assert.strictEqual(vaultAccount.reservedIdle.toString(), '60');
+
+      const sessionAccount = await vaultProgram.account.withdrawalSession.fetch(session);
+      assert.strictEqual(sessionAccount.reservedIdle.toString(), '60');
+      assert.strictEqual(sessionAccount.loss.toString(), '0');
+    });
+
+    it("finalize withdraw", async () => {
+      const provider = AnchorProvider.env();
+
+      await vaultProgram.methods.finalizeWithdraw()
+        .accounts({
+          vault: accounts.vault,
+          userTokenAccount: sessionUserTokenAccount,
+          user: sessionUser.publicKey,
+        })
+        .signers([sessionUser])
+        .rpc();
+
+      const sessionAccount = await vaultProgram.account.withdrawalSession.fetchNullable(session);
+      assert.isNull(sessionAccount);
+
+      const vaultAccount = await vaultProgram.account.vault.fetch(accounts.vault);
+      assert.strictEqual(vaultAccount.totalIdle.toString(), '0');
+      assert.strictEqual(vaultAccount.reservedIdle.toString(), '0');
+
+      const userTokenAccountInfo = await token.getAccount(provider.connection, sessionUserTokenAccount);
+      assert.strictEqual(userTokenAccountInfo.amount.toString(), '960');
+
+      const sharesMintInfo = await token.getMint(provider.connection, accounts.sharesMint);
+      assert.strictEqual(sharesMintInfo.supply.toString(), '40');
+    });
+
+    it("cancel withdraw", async () => {
+      const provider = AnchorProvider.env();
+
+      await vaultProgram.methods.startWithdraw(new BN(40), null)
+        .accounts({
+          vault: accounts.vault,
+          userSharesAccount: sessionUserSharesAccount,
+          user: sessionUser.publicKey,
+        })
+        .signers([sessionUser])
+        .rpc();
+
+      await vaultProgram.methods.continueWithdraw(remainingAccountsMap)
+        .accounts({
+          vault: accounts.vault,
+          user: sessionUser.publicKey,
+        })
+        .remainingAccounts(strategyRemainingAccounts())
+        .signers([sessionUser])
+        .rpc();
+
+      let vaultAccount = await vaultProgram.account.vault.fetch(accounts.vault);
+      assert.strictEqual(vaultAccount.reservedIdle.toString(), '40');
+
+      await vaultProgram.methods.cancelWithdraw()
+        .accounts({
+          vault: accounts.vault,
+          userSharesAccount: sessionUserSharesAccount,
+          user: sessionUser.publicKey,
+        })
+        .signers([sessionUser])
+        .rpc();
+
+      const sessionAccount = await vaultProgram.account.withdrawalSession.fetchNullable(session);
+      assert.isNull(sessionAccount);
+
+      // the pulled funds stay in the vault idle and are released
+      vaultAccount = await vaultProgram.account.vault.fetch(accounts.vault);
+      assert.strictEqual(vaultAccount.totalIdle.toString(), '40');
+      assert.strictEqual(vaultAccount.totalDebt.toString(), '0');
+      assert.strictEqual(vaultAccount.reservedIdle.toString(), '0');
+
+      const userSharesAccountInfo = await token.getAccount(provider.connection, sessionUserSharesAccount);
+      assert.strictEqual(userSharesAccountInfo.amount.toString(), '40');
+    });
+  });
 });